@@ -0,0 +1,68 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rusty_rosalind::align::{banded_edit_distance, edit_distance, myers_edit_distance};
+use rusty_rosalind::nucleotides::dna::DNA;
+use rusty_rosalind::strands::Strand;
+
+const LENGTH: usize = 10_000;
+/// the band of the banded implementation, wide enough for the mutations below
+const MAX_DISTANCE: usize = 500;
+
+/// a pseudo-random number generator, so runs are comparable
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        self.0 >> 32
+    }
+
+    fn nucleotide(&mut self) -> DNA {
+        [DNA::A, DNA::C, DNA::G, DNA::T][(self.next() % 4) as usize]
+    }
+}
+
+/// generates a pseudo-random strand and a copy with about 2% of its nucleotides
+/// substituted, inserted or deleted, like a read of it
+fn strands() -> (Strand<DNA>, Strand<DNA>) {
+    let mut random = Random(1);
+    let original: Vec<DNA> = (0..LENGTH).map(|_| random.nucleotide()).collect();
+    let mut mutated = Vec::with_capacity(LENGTH);
+    for nucleotide in &original {
+        match random.next() % 150 {
+            0 => mutated.push(random.nucleotide()),
+            1 => mutated.extend([*nucleotide, random.nucleotide()]),
+            2 => (),
+            _ => mutated.push(*nucleotide),
+        }
+    }
+    (Strand::new(original), Strand::new(mutated))
+}
+
+fn bench_edit_distance(c: &mut Criterion) {
+    let (strand, mutated) = strands();
+    println!(
+        "edit distance between {} and {} nucleotides: {}",
+        strand.len(),
+        mutated.len(),
+        myers_edit_distance(&strand, &mutated)
+    );
+
+    let mut group = c.benchmark_group("edit_distance");
+    group.sample_size(10);
+    group.bench_function("full", |b| {
+        b.iter(|| edit_distance(black_box(&strand), black_box(&mutated)))
+    });
+    group.bench_function("banded", |b| {
+        b.iter(|| banded_edit_distance(black_box(&strand), black_box(&mutated), MAX_DISTANCE))
+    });
+    group.bench_function("myers", |b| {
+        b.iter(|| myers_edit_distance(black_box(&strand), black_box(&mutated)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_edit_distance);
+criterion_main!(benches);
@@ -0,0 +1,97 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rusty_rosalind::nucleotides::dna::DNA;
+use rusty_rosalind::strands::packed::PackedStrand;
+use rusty_rosalind::strands::Strand;
+
+const LENGTH: usize = 1_000_000;
+
+/// generates a pseudo-random strand, so runs are comparable
+fn random_strand(seed: u64) -> Strand<DNA> {
+    let mut state = seed;
+    let nucleotides = (0..LENGTH)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            [DNA::A, DNA::C, DNA::G, DNA::T][(state >> 62) as usize]
+        })
+        .collect();
+    Strand::new(nucleotides)
+}
+
+fn bench_packed_strand(c: &mut Criterion) {
+    let strand = random_strand(1);
+    let other = random_strand(2);
+    let packed = PackedStrand::from(&strand);
+    let packed_other = PackedStrand::from(&other);
+
+    println!(
+        "memory for {} nucleotides: Vec<DNA> {} bytes, packed {} bytes",
+        LENGTH,
+        LENGTH * std::mem::size_of::<DNA>(),
+        std::mem::size_of_val(packed.as_words())
+    );
+
+    let mut group = c.benchmark_group("index");
+    group.bench_function("vec", |b| {
+        b.iter(|| {
+            (0..LENGTH)
+                .step_by(7)
+                .filter(|i| strand[*i] == DNA::G)
+                .count()
+        })
+    });
+    group.bench_function("packed", |b| {
+        b.iter(|| {
+            (0..LENGTH)
+                .step_by(7)
+                .filter(|i| packed[*i] == DNA::G)
+                .count()
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("iterate");
+    group.bench_function("vec", |b| {
+        b.iter(|| {
+            (0..LENGTH)
+                .filter(|i| black_box(&strand)[*i] == DNA::G)
+                .count()
+        })
+    });
+    group.bench_function("packed", |b| {
+        b.iter(|| black_box(&packed).iter().filter(|n| *n == DNA::G).count())
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("reverse_compliment");
+    group.bench_function("vec", |b| {
+        b.iter(|| black_box(&strand).reverse_compliment())
+    });
+    group.bench_function("packed", |b| {
+        b.iter(|| black_box(&packed).reverse_compliment())
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("count_nucleotides");
+    group.bench_function("vec", |b| b.iter(|| black_box(&strand).count_nucleotides()));
+    group.bench_function("packed", |b| {
+        b.iter(|| black_box(&packed).count_nucleotides())
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("get_gc_content");
+    group.bench_function("vec", |b| b.iter(|| black_box(&strand).get_gc_content()));
+    group.bench_function("packed", |b| b.iter(|| black_box(&packed).get_gc_content()));
+    group.finish();
+
+    let mut group = c.benchmark_group("distance");
+    group.bench_function("vec", |b| b.iter(|| black_box(&strand).distance(&other)));
+    group.bench_function("packed", |b| {
+        b.iter(|| black_box(&packed).distance(&packed_other))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_packed_strand);
+criterion_main!(benches);
@@ -0,0 +1,142 @@
+use crate::nucleotides::Nucleotide;
+use std::fmt;
+use std::ops::Range;
+
+/// What one column of an alignment does to the first strand to turn it into the second
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Operation {
+    /// the nucleotides match, including through ambiguity codes
+    Match,
+    Mismatch,
+    /// a nucleotide of the first strand is aligned to a gap
+    Insertion,
+    /// a nucleotide of the second strand is aligned to a gap
+    Deletion,
+}
+
+impl Operation {
+    /// returns the extended CIGAR code of the operation
+    pub fn code(&self) -> char {
+        match self {
+            Operation::Match => '=',
+            Operation::Mismatch => 'X',
+            Operation::Insertion => 'I',
+            Operation::Deletion => 'D',
+        }
+    }
+}
+
+/// The alignment of a range of one strand to a range of another
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Alignment<T: Nucleotide> {
+    pub score: i32,
+    /// the aligned nucleotides of the first strand, with None for gaps
+    pub aligned_1: Vec<Option<T>>,
+    /// the aligned nucleotides of the second strand, with None for gaps
+    pub aligned_2: Vec<Option<T>>,
+    /// the positions of the first strand covered by the alignment
+    pub range_1: Range<usize>,
+    /// the positions of the second strand covered by the alignment
+    pub range_2: Range<usize>,
+}
+
+impl<T: Nucleotide> Alignment<T> {
+    /// Returns the number of columns of the alignment
+    pub fn len(&self) -> usize {
+        self.aligned_1.len()
+    }
+
+    /// Returns true if the alignment has no columns
+    pub fn is_empty(&self) -> bool {
+        self.aligned_1.is_empty()
+    }
+
+    /// returns the operation of each column
+    pub fn operations(&self) -> Vec<Operation> {
+        self.aligned_1
+            .iter()
+            .zip(self.aligned_2.iter())
+            .map(|pair| match pair {
+                (Some(n1), Some(n2)) if n1.matches(n2) => Operation::Match,
+                (Some(_), Some(_)) => Operation::Mismatch,
+                (Some(_), None) => Operation::Insertion,
+                _ => Operation::Deletion,
+            })
+            .collect()
+    }
+
+    /// returns the number of mismatches and gap columns
+    pub fn edits(&self) -> usize {
+        self.operations()
+            .iter()
+            .filter(|op| **op != Operation::Match)
+            .count()
+    }
+
+    /// returns the CIGAR string of the alignment of the first strand against the
+    /// second, with M for both matches and mismatches
+    pub fn cigar(&self) -> String {
+        run_lengths(self.operations().iter().map(|op| match op {
+            Operation::Match | Operation::Mismatch => 'M',
+            _ => op.code(),
+        }))
+    }
+
+    /// returns the CIGAR string using = for matches and X for mismatches
+    pub fn extended_cigar(&self) -> String {
+        run_lengths(self.operations().iter().map(Operation::code))
+    }
+
+    /// returns a line annotating each column, with | for matches, . for mismatches
+    /// and a space for gaps, to write between the aligned strands
+    pub fn annotation(&self) -> String {
+        self.operations()
+            .iter()
+            .map(|op| match op {
+                Operation::Match => '|',
+                Operation::Mismatch => '.',
+                Operation::Insertion | Operation::Deletion => ' ',
+            })
+            .collect()
+    }
+
+    /// returns the two aligned strands, with - for gaps
+    pub fn aligned_strings(&self) -> (String, String) {
+        (
+            gapped_string(&self.aligned_1),
+            gapped_string(&self.aligned_2),
+        )
+    }
+}
+
+/// writes each code with the number of times it repeats before it
+fn run_lengths(codes: impl Iterator<Item = char>) -> String {
+    let mut cigar = String::new();
+    let mut codes = codes.peekable();
+    while let Some(code) = codes.next() {
+        let mut count = 1;
+        while codes.next_if_eq(&code).is_some() {
+            count += 1;
+        }
+        cigar.push_str(&format!("{}{}", count, code));
+    }
+    cigar
+}
+
+fn gapped_string<T: Nucleotide>(aligned: &[Option<T>]) -> String {
+    aligned
+        .iter()
+        .map(|n| match n {
+            Some(n) => format!("{:?}", n),
+            None => "-".to_string(),
+        })
+        .collect()
+}
+
+/// Alignments are written as the two aligned strands on separate lines
+impl<T: Nucleotide> fmt::Display for Alignment<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (aligned_1, aligned_2) = self.aligned_strings();
+        write!(f, "{}\n{}", aligned_1, aligned_2)
+    }
+}
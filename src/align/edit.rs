@@ -0,0 +1,302 @@
+use crate::nucleotides::Nucleotide;
+use crate::strands::StrandSlice;
+use std::collections::HashMap;
+
+/// returns the Levenshtein distance between the strands: the fewest substitutions,
+/// insertions and deletions turning one into the other. Ambiguity codes are only
+/// counted as a substitution if they cannot stand for the same nucleotide.
+///
+/// Fills the full dynamic programming matrix a row at a time, in O(nm) time and O(m) space.
+pub fn edit_distance<'a, 'b, T>(
+    strand_1: impl Into<StrandSlice<'a, T>>,
+    strand_2: impl Into<StrandSlice<'b, T>>,
+) -> usize
+where
+    T: Nucleotide + 'a + 'b,
+{
+    let (strand_1, strand_2) = (strand_1.into(), strand_2.into());
+    let mut previous: Vec<usize> = (0..=strand_2.len()).collect();
+    let mut current = vec![0; strand_2.len() + 1];
+    for (i, n1) in strand_1.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, n2) in strand_2.iter().enumerate() {
+            let substitution = previous[j] + usize::from(!n1.matches(n2));
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[strand_2.len()]
+}
+
+/// returns the fewest substitutions, insertions and deletions turning some suffix of
+/// the first strand into the prefix of the second with `length` nucleotides, or None
+/// if the second strand is shorter than that. The suffix may be any length.
+///
+/// Like `edit_distance`, but starting anywhere in the first strand for free, in
+/// O(n * length) time.
+pub fn overlap_edit_distance<'a, 'b, T>(
+    strand_1: impl Into<StrandSlice<'a, T>>,
+    strand_2: impl Into<StrandSlice<'b, T>>,
+    length: usize,
+) -> Option<usize>
+where
+    T: Nucleotide + 'a + 'b,
+{
+    let (strand_1, strand_2) = (strand_1.into(), strand_2.into());
+    if strand_2.len() < length {
+        return None;
+    }
+    let prefix = strand_2.slice(0..length);
+    let mut previous: Vec<usize> = (0..=length).collect();
+    let mut current = vec![0; length + 1];
+    for n1 in strand_1.iter() {
+        // the suffix can start after this nucleotide for free
+        current[0] = 0;
+        for (j, n2) in prefix.iter().enumerate() {
+            let substitution = previous[j] + usize::from(!n1.matches(n2));
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    Some(previous[length])
+}
+
+/// returns the edit distance between the strands if it is at most `k`, or None.
+///
+/// Only the band of cells within `k` of the diagonal can be on a path costing at
+/// most `k`, so only those are filled, in O(kn) time. Stops early once every cell
+/// of a row costs more than `k`.
+pub fn banded_edit_distance<'a, 'b, T>(
+    strand_1: impl Into<StrandSlice<'a, T>>,
+    strand_2: impl Into<StrandSlice<'b, T>>,
+    k: usize,
+) -> Option<usize>
+where
+    T: Nucleotide + 'a + 'b,
+{
+    let (strand_1, strand_2) = (strand_1.into(), strand_2.into());
+    let (n, m) = (strand_1.len(), strand_2.len());
+    if n.abs_diff(m) > k {
+        return None;
+    }
+    // cells outside the band cost more than k, whatever their real cost
+    let outside = k + 1;
+    let mut previous: Vec<usize> = (0..=m).map(|j| j.min(outside)).collect();
+    let mut current = vec![outside; m + 1];
+    for i in 1..=n {
+        let first = i.saturating_sub(k);
+        let last = (i + k).min(m);
+        let mut row_min = outside;
+        if first == 0 {
+            current[0] = i.min(outside);
+            row_min = current[0];
+        } else {
+            current[first - 1] = outside;
+        }
+        for j in first.max(1)..=last {
+            let substitution =
+                previous[j - 1] + usize::from(!strand_1[i - 1].matches(&strand_2[j - 1]));
+            let cost = substitution
+                .min(previous[j] + 1)
+                .min(current[j - 1] + 1)
+                .min(outside);
+            current[j] = cost;
+            row_min = row_min.min(cost);
+        }
+        if last < m {
+            current[last + 1] = outside;
+        }
+        if row_min > k {
+            return None;
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    Some(previous[m]).filter(|distance| *distance <= k)
+}
+
+/// the bits of each block of the first strand, the vertical dimension of the matrix
+const WORD: usize = u64::BITS as usize;
+
+/// returns the edit distance between the strands with Myers' bit-parallel algorithm,
+/// as extended to blocks by Hyyrö. Each column of the matrix is encoded as the bit
+/// vectors of its positive and negative vertical differences, and is computed from
+/// the previous column a 64 bit word at a time, in O(nm / 64) time.
+pub fn myers_edit_distance<'a, 'b, T>(
+    strand_1: impl Into<StrandSlice<'a, T>>,
+    strand_2: impl Into<StrandSlice<'b, T>>,
+) -> usize
+where
+    T: Nucleotide + 'a + 'b,
+{
+    let (strand_1, strand_2) = (strand_1.into(), strand_2.into());
+    let m = strand_1.len();
+    if m == 0 {
+        return strand_2.len();
+    }
+    let blocks = m.div_ceil(WORD);
+    // the bit of the last row of the last block
+    let last_bit = 1 << ((m - 1) % WORD);
+
+    // for each nucleotide of the second strand, the positions of the first it matches
+    let mut matches: HashMap<T, Vec<u64>> = HashMap::new();
+    for nucleotide in strand_2.iter() {
+        matches.entry(*nucleotide).or_insert_with(|| {
+            let mut bits = vec![0; blocks];
+            for (i, n) in strand_1.iter().enumerate() {
+                if n.matches(nucleotide) {
+                    bits[i / WORD] |= 1 << (i % WORD);
+                }
+            }
+            bits
+        });
+    }
+
+    // the first column costs one more in each row than the last
+    let mut positive = vec![u64::MAX; blocks];
+    let mut negative = vec![0; blocks];
+    let mut distance = m;
+    for nucleotide in strand_2.iter() {
+        let eq = &matches[nucleotide];
+        // the first row costs one more in each column than the last
+        let mut carry = 1;
+        for block in 0..blocks {
+            let high_bit = if block == blocks - 1 {
+                last_bit
+            } else {
+                1 << (WORD - 1)
+            };
+            carry = advance_block(
+                &mut positive[block],
+                &mut negative[block],
+                eq[block],
+                carry,
+                high_bit,
+            );
+        }
+        distance = distance.wrapping_add_signed(carry as isize);
+    }
+    distance
+}
+
+/// computes the next column of a block from its matches against the nucleotide of the
+/// column and the horizontal difference into its first row. Returns the horizontal
+/// difference out of the row of `high_bit`.
+fn advance_block(
+    positive: &mut u64,
+    negative: &mut u64,
+    eq: u64,
+    carry_in: i8,
+    high_bit: u64,
+) -> i8 {
+    let (pv, mv) = (*positive, *negative);
+    let xv = eq | mv;
+    let eq = eq | u64::from(carry_in < 0);
+    let xh = ((eq & pv).wrapping_add(pv) ^ pv) | eq;
+    let mut ph = mv | !(xh | pv);
+    let mut mh = pv & xh;
+    let carry_out = if ph & high_bit != 0 {
+        1
+    } else if mh & high_bit != 0 {
+        -1
+    } else {
+        0
+    };
+    ph <<= 1;
+    mh <<= 1;
+    mh |= u64::from(carry_in < 0);
+    ph |= u64::from(carry_in > 0);
+    *positive = mh | !(xv | ph);
+    *negative = ph & xv;
+    carry_out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::RosalindError;
+    use crate::nucleotides::dna::DNA;
+    use crate::nucleotides::protein::AminoAcid;
+    use crate::strands::Strand;
+
+    /// generates a pseudo-random strand, so failures can be reproduced
+    fn random_strand(seed: u64, length: usize) -> Strand<DNA> {
+        let mut state = seed;
+        let nucleotides = (0..length)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                [DNA::A, DNA::C, DNA::G, DNA::T][(state >> 62) as usize]
+            })
+            .collect();
+        Strand::new(nucleotides)
+    }
+
+    #[test]
+    fn test_edit_distance() -> Result<(), RosalindError> {
+        let strand_1: Strand<AminoAcid> = "PLEASANTLY".parse()?;
+        let strand_2: Strand<AminoAcid> = "MEANLY".parse()?;
+        assert_eq!(edit_distance(&strand_1, &strand_2), 5);
+        assert_eq!(myers_edit_distance(&strand_1, &strand_2), 5);
+        assert_eq!(banded_edit_distance(&strand_1, &strand_2, 5), Some(5));
+        assert_eq!(banded_edit_distance(&strand_1, &strand_2, 4), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_overlap_edit_distance() -> Result<(), RosalindError> {
+        let strand_1: Strand<DNA> = "GGGGACGTAC".parse()?;
+        let strand_2: Strand<DNA> = "ACGTACTTTT".parse()?;
+        assert_eq!(overlap_edit_distance(&strand_1, &strand_2, 6), Some(0));
+        // ACGTAC against ACGTACT, or CGTAC against ACGTACT
+        assert_eq!(overlap_edit_distance(&strand_1, &strand_2, 7), Some(1));
+        // ACGTAC with one substitution
+        let strand_3: Strand<DNA> = "ACTTACTTTT".parse()?;
+        assert_eq!(overlap_edit_distance(&strand_1, &strand_3, 6), Some(1));
+        assert_eq!(overlap_edit_distance(&strand_1, &strand_3, 11), None);
+        for length in 0..=strand_2.len() {
+            // no worse than aligning against the whole of the first strand
+            let whole = edit_distance(&strand_1, strand_2.slice(0..length));
+            let overlap = overlap_edit_distance(&strand_1, &strand_2, length).unwrap();
+            assert!(overlap <= whole && overlap <= length);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = "GATTACA".parse()?;
+        let empty: Strand<DNA> = Strand::new(vec![]);
+        assert_eq!(edit_distance(&strand, &empty), 7);
+        assert_eq!(myers_edit_distance(&empty, &strand), 7);
+        assert_eq!(myers_edit_distance(&strand, &empty), 7);
+        assert_eq!(banded_edit_distance(&empty, &empty, 0), Some(0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_implementations_agree() {
+        for seed in 0..50 {
+            // lengths either side of the 64 bit blocks
+            let strand_1 = random_strand(seed, (seed as usize * 37) % 200);
+            let strand_2 = random_strand(seed + 1000, (seed as usize * 53) % 200);
+            let distance = edit_distance(&strand_1, &strand_2);
+            assert_eq!(myers_edit_distance(&strand_1, &strand_2), distance);
+            assert_eq!(myers_edit_distance(&strand_2, &strand_1), distance);
+            assert_eq!(
+                banded_edit_distance(&strand_1, &strand_2, distance),
+                Some(distance)
+            );
+            assert_eq!(
+                banded_edit_distance(&strand_1, &strand_2, 200),
+                Some(distance)
+            );
+            if distance > 0 {
+                assert_eq!(
+                    banded_edit_distance(&strand_1, &strand_2, distance - 1),
+                    None
+                );
+            }
+        }
+    }
+}
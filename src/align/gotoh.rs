@@ -0,0 +1,203 @@
+use super::{Alignment, GapPenalty, Mode, SubstitutionMatrix};
+use crate::nucleotides::Nucleotide;
+use crate::strands::StrandSlice;
+
+/// low enough to never be chosen, without overflowing when penalties are subtracted
+pub(super) const NEG_INFINITY: i32 = i32::MIN / 4;
+
+/// The matrix an alignment ending at a cell is in
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum State {
+    /// ends with two aligned nucleotides
+    Aligned,
+    /// ends with a nucleotide of the first strand aligned to a gap
+    Insertion,
+    /// ends with a nucleotide of the second strand aligned to a gap
+    Deletion,
+}
+
+/// The three dynamic programming matrices of Gotoh's algorithm, stored row by row
+struct Matrices {
+    width: usize,
+    aligned: Vec<i32>,
+    insertion: Vec<i32>,
+    deletion: Vec<i32>,
+}
+
+impl Matrices {
+    fn get(&self, state: State, i: usize, j: usize) -> i32 {
+        let index = i * self.width + j;
+        match state {
+            State::Aligned => self.aligned[index],
+            State::Insertion => self.insertion[index],
+            State::Deletion => self.deletion[index],
+        }
+    }
+
+    /// returns the state with the best score at the cell, preferring aligned nucleotides
+    fn best(&self, i: usize, j: usize) -> (State, i32) {
+        [State::Aligned, State::Insertion, State::Deletion]
+            .into_iter()
+            .map(|state| (state, self.get(state, i, j)))
+            .fold((State::Aligned, NEG_INFINITY), |best, next| {
+                if next.1 > best.1 {
+                    next
+                } else {
+                    best
+                }
+            })
+    }
+}
+
+/// aligns the strands with Gotoh's three matrix dynamic programming, in O(nm) time
+/// and space. Positions marked as used in `used_1` and `used_2` cannot be part of
+/// the alignment.
+pub(super) fn align<T, M>(
+    strand_1: StrandSlice<'_, T>,
+    strand_2: StrandSlice<'_, T>,
+    matrix: &M,
+    gap: GapPenalty,
+    mode: Mode,
+    used_1: &[bool],
+    used_2: &[bool],
+) -> Alignment<T>
+where
+    T: Nucleotide,
+    M: SubstitutionMatrix<T>,
+{
+    let (open, extend) = gap.open_extend();
+    let ends = mode.free_ends();
+    let local = mode == Mode::Local;
+    let (n, m) = (strand_1.len(), strand_2.len());
+    let width = m + 1;
+    let size = (n + 1) * width;
+    let mut matrices = Matrices {
+        width,
+        aligned: vec![NEG_INFINITY; size],
+        insertion: vec![NEG_INFINITY; size],
+        deletion: vec![NEG_INFINITY; size],
+    };
+    let score = |i: usize, j: usize| matrix.score(&strand_1[i - 1], &strand_2[j - 1]);
+    let used_1 = |i: usize| used_1.get(i - 1).copied().unwrap_or(false);
+    let used_2 = |j: usize| used_2.get(j - 1).copied().unwrap_or(false);
+
+    for i in 0..=n {
+        for j in 0..=m {
+            let index = i * width + j;
+            matrices.aligned[index] = if i > 0 && j > 0 && (used_1(i) || used_2(j)) {
+                NEG_INFINITY
+            } else if i > 0 && j > 0 {
+                let (_, previous) = matrices.best(i - 1, j - 1);
+                let previous = if local { previous.max(0) } else { previous };
+                previous + score(i, j)
+            } else if (i == 0 || ends.start_1 || local) && (j == 0 || ends.start_2 || local) {
+                // alignments may start at the edge
+                0
+            } else {
+                NEG_INFINITY
+            };
+            if i > 0 && !used_1(i) {
+                let above = index - width;
+                matrices.insertion[index] = (matrices.aligned[above] - open)
+                    .max(matrices.insertion[above] - extend)
+                    .max(matrices.deletion[above] - open);
+            }
+            if j > 0 && !used_2(j) {
+                let left = index - 1;
+                matrices.deletion[index] = (matrices.aligned[left] - open)
+                    .max(matrices.deletion[left] - extend)
+                    .max(matrices.insertion[left] - open);
+            }
+        }
+    }
+
+    // the cells the alignment may end at, with the first best cell winning
+    let mut end = if local {
+        // the empty alignment
+        (0, 0, (State::Aligned, 0))
+    } else {
+        (n, m, matrices.best(n, m))
+    };
+    let mut consider = |i: usize, j: usize, best: (State, i32)| {
+        if best.1 > (end.2).1 {
+            end = (i, j, best);
+        }
+    };
+    if local {
+        for i in 0..=n {
+            for j in 0..=m {
+                consider(i, j, (State::Aligned, matrices.get(State::Aligned, i, j)));
+            }
+        }
+    } else {
+        if ends.end_1 {
+            (0..=n).for_each(|i| consider(i, m, matrices.best(i, m)));
+        }
+        if ends.end_2 {
+            (0..=m).for_each(|j| consider(n, j, matrices.best(n, j)));
+        }
+    }
+    let (end_1, end_2, (mut state, total)) = end;
+
+    let mut aligned_1 = vec![];
+    let mut aligned_2 = vec![];
+    let (mut i, mut j) = (end_1, end_2);
+    loop {
+        let current = matrices.get(state, i, j);
+        match state {
+            State::Aligned => {
+                if i == 0 || j == 0 || (local && current == score(i, j)) {
+                    if i > 0 && j > 0 {
+                        i -= 1;
+                        j -= 1;
+                        aligned_1.push(Some(strand_1[i]));
+                        aligned_2.push(Some(strand_2[j]));
+                    }
+                    break;
+                }
+                let previous = current - score(i, j);
+                state = [State::Aligned, State::Insertion, State::Deletion]
+                    .into_iter()
+                    .find(|s| matrices.get(*s, i - 1, j - 1) == previous)
+                    .expect("an aligned cell follows one of the three matrices");
+                i -= 1;
+                j -= 1;
+                aligned_1.push(Some(strand_1[i]));
+                aligned_2.push(Some(strand_2[j]));
+            }
+            State::Insertion => {
+                state = if current == matrices.get(State::Aligned, i - 1, j) - open {
+                    State::Aligned
+                } else if current == matrices.get(State::Insertion, i - 1, j) - extend {
+                    State::Insertion
+                } else {
+                    State::Deletion
+                };
+                i -= 1;
+                aligned_1.push(Some(strand_1[i]));
+                aligned_2.push(None);
+            }
+            State::Deletion => {
+                state = if current == matrices.get(State::Aligned, i, j - 1) - open {
+                    State::Aligned
+                } else if current == matrices.get(State::Deletion, i, j - 1) - extend {
+                    State::Deletion
+                } else {
+                    State::Insertion
+                };
+                j -= 1;
+                aligned_1.push(None);
+                aligned_2.push(Some(strand_2[j]));
+            }
+        }
+    }
+    aligned_1.reverse();
+    aligned_2.reverse();
+    Alignment {
+        score: total,
+        aligned_1,
+        aligned_2,
+        range_1: i..end_1,
+        range_2: j..end_2,
+    }
+}
@@ -0,0 +1,287 @@
+use super::gotoh::{self, NEG_INFINITY};
+use super::{Aligner, Alignment, Mode, SubstitutionMatrix};
+use crate::fasta::Fasta;
+use crate::nucleotides::Nucleotide;
+use crate::strands::StrandSlice;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+/// the number of query positions scored at once. Eight 32 bit scores fill a 256 bit
+/// vector register, and the loops over lanes are simple enough to be auto-vectorized.
+const LANES: usize = 8;
+
+type Lanes = [i32; LANES];
+
+/// moves each lane's score to the next lane, filling the first lane
+fn shift(lanes: &Lanes, fill: i32) -> Lanes {
+    let mut shifted = [fill; LANES];
+    shifted[1..].copy_from_slice(&lanes[..LANES - 1]);
+    shifted
+}
+
+impl<M> Aligner<M> {
+    /// returns up to `k` local alignments from best to worst, none of which share
+    /// a position of either strand with a better one. The mode of the aligner is ignored.
+    pub fn local_alignments<'a, 'b, T>(
+        &self,
+        strand_1: impl Into<StrandSlice<'a, T>>,
+        strand_2: impl Into<StrandSlice<'b, T>>,
+        k: usize,
+    ) -> Vec<Alignment<T>>
+    where
+        T: Nucleotide + 'a + 'b,
+        M: SubstitutionMatrix<T>,
+    {
+        let (strand_1, strand_2) = (strand_1.into(), strand_2.into());
+        let mut used_1 = vec![false; strand_1.len()];
+        let mut used_2 = vec![false; strand_2.len()];
+        let mut alignments = vec![];
+        while alignments.len() < k {
+            let alignment = gotoh::align(
+                strand_1,
+                strand_2,
+                &self.matrix,
+                self.gap,
+                Mode::Local,
+                &used_1,
+                &used_2,
+            );
+            if alignment.is_empty() {
+                break;
+            }
+            used_1[alignment.range_1.clone()].fill(true);
+            used_2[alignment.range_2.clone()].fill(true);
+            alignments.push(alignment);
+        }
+        alignments
+    }
+
+    /// prepares the query for scoring local alignments against many targets
+    pub fn local_scanner<'a, T>(
+        &'a self,
+        query: impl Into<StrandSlice<'a, T>>,
+    ) -> LocalScanner<'a, T, M>
+    where
+        T: Nucleotide + 'a,
+        M: SubstitutionMatrix<T>,
+    {
+        let query = query.into();
+        let (open, extend) = self.gap.open_extend();
+        LocalScanner {
+            query,
+            matrix: &self.matrix,
+            open,
+            extend,
+            segments: query.len().div_ceil(LANES),
+            profiles: HashMap::new(),
+        }
+    }
+}
+
+/// A query prepared for finding the best local alignment scores against many targets,
+/// with Farrar's striped Smith-Waterman algorithm. Only scores are computed, in
+/// O(nm) time and O(n) space, so the best targets can then be aligned with an Aligner.
+///
+/// The query is split into `segments` stripes across LANES lanes, each lane holding
+/// `segments` consecutive positions, so position `lane * segments + segment` is scored
+/// in its lane of stripe `segment`. The lanes of a stripe do not depend on each other,
+/// so each stripe can be scored at once by a vector instruction.
+pub struct LocalScanner<'a, T: Nucleotide, M> {
+    query: StrandSlice<'a, T>,
+    matrix: &'a M,
+    open: i32,
+    extend: i32,
+    /// the number of query positions in each lane
+    segments: usize,
+    /// the striped scores of the query against each nucleotide of the targets so far
+    profiles: HashMap<T, Vec<Lanes>>,
+}
+
+impl<'a, T: Nucleotide, M: SubstitutionMatrix<T>> LocalScanner<'a, T, M> {
+    /// returns the striped scores of the query against the nucleotide. Positions past
+    /// the end of the query never score.
+    fn profile(&self, nucleotide: &T) -> Vec<Lanes> {
+        (0..self.segments)
+            .map(|segment| {
+                let mut scores = [NEG_INFINITY; LANES];
+                for (lane, score) in scores.iter_mut().enumerate() {
+                    let position = lane * self.segments + segment;
+                    if position < self.query.len() {
+                        *score = self.matrix.score(&self.query[position], nucleotide);
+                    }
+                }
+                scores
+            })
+            .collect()
+    }
+
+    /// returns the score of the best local alignment of the query against the target,
+    /// which is the same as the score found by an Aligner in local mode
+    pub fn score<'b>(&mut self, target: impl Into<StrandSlice<'b, T>>) -> i32
+    where
+        T: 'b,
+    {
+        let target = target.into();
+        let segments = self.segments;
+        if segments == 0 {
+            return 0;
+        }
+        for nucleotide in target.iter() {
+            if !self.profiles.contains_key(nucleotide) {
+                let profile = self.profile(nucleotide);
+                self.profiles.insert(*nucleotide, profile);
+            }
+        }
+        let (open, extend) = (self.open, self.extend);
+
+        // the scores of the previous and current target positions, and of the gaps
+        // in the query ending at the next position
+        let mut previous = vec![[0; LANES]; segments];
+        let mut current = vec![[0; LANES]; segments];
+        let mut gaps = vec![[NEG_INFINITY; LANES]; segments];
+        let mut best = [0; LANES];
+        for nucleotide in target.iter() {
+            let profile = &self.profiles[nucleotide];
+            // the gaps in the target ending at each lane's next query position
+            let mut target_gaps = [NEG_INFINITY; LANES];
+            let mut diagonal = shift(&previous[segments - 1], 0);
+            for segment in 0..segments {
+                for lane in 0..LANES {
+                    let cell = (diagonal[lane] + profile[segment][lane])
+                        .max(gaps[segment][lane])
+                        .max(target_gaps[lane])
+                        .max(0);
+                    current[segment][lane] = cell;
+                    best[lane] = best[lane].max(cell);
+                    gaps[segment][lane] = (gaps[segment][lane] - extend).max(cell - open);
+                    target_gaps[lane] = (target_gaps[lane] - extend).max(cell - open);
+                }
+                diagonal = previous[segment];
+            }
+
+            // gaps in the target crossing from one lane to the next were not scored,
+            // so they are carried over until they can no longer improve a score
+            let mut segment = 0;
+            target_gaps = shift(&target_gaps, NEG_INFINITY);
+            while (0..LANES).any(|lane| target_gaps[lane] > current[segment][lane] - open) {
+                for lane in 0..LANES {
+                    let cell = current[segment][lane].max(target_gaps[lane]);
+                    current[segment][lane] = cell;
+                    best[lane] = best[lane].max(cell);
+                    gaps[segment][lane] = gaps[segment][lane].max(cell - open);
+                    target_gaps[lane] -= extend;
+                }
+                segment += 1;
+                if segment == segments {
+                    segment = 0;
+                    target_gaps = shift(&target_gaps, NEG_INFINITY);
+                }
+            }
+            std::mem::swap(&mut previous, &mut current);
+        }
+        best.into_iter().max().unwrap_or(0)
+    }
+
+    /// returns every record with the score of its best local alignment against the
+    /// query, from best to worst. Records with the same score keep their order.
+    pub fn scan<'r>(&mut self, records: &'r [Fasta<T>]) -> Vec<(&'r Fasta<T>, i32)> {
+        let mut hits: Vec<(&Fasta<T>, i32)> = records
+            .iter()
+            .map(|record| (record, self.score(&record.strand)))
+            .collect();
+        hits.sort_by_key(|(_, score)| Reverse(*score));
+        hits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::align::{Blosum62, GapPenalty, MatchMismatch};
+    use crate::error::RosalindError;
+    use crate::fasta::read_records;
+    use crate::nucleotides::dna::DNA;
+    use crate::nucleotides::protein::AminoAcid;
+    use crate::strands::Strand;
+
+    /// generates a pseudo-random protein, so failures can be reproduced
+    fn random_protein(seed: u64, length: usize) -> Strand<AminoAcid> {
+        let mut state = seed;
+        let amino_acids = (0..length)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                // a small alphabet so there is something to align
+                AminoAcid::STANDARD[(state >> 61) as usize]
+            })
+            .collect();
+        Strand::new(amino_acids)
+    }
+
+    #[test]
+    fn test_local_alignments() -> Result<(), RosalindError> {
+        let strand_1: Strand<DNA> = "GATTACATTTTTTTCCGGAACC".parse()?;
+        let strand_2: Strand<DNA> = "CCGGAACCAAAAAAGATTACA".parse()?;
+        let aligner = Aligner::new(MatchMismatch::new(1, -3), GapPenalty::Linear(5));
+        let alignments = aligner.local_alignments(&strand_1, &strand_2, 3);
+        assert_eq!(alignments.len(), 2);
+        assert_eq!(alignments[0].score, 8);
+        assert_eq!(
+            (alignments[0].range_1.clone(), alignments[0].range_2.clone()),
+            (14..22, 0..8)
+        );
+        assert_eq!(alignments[1].score, 7);
+        assert_eq!(
+            (alignments[1].range_1.clone(), alignments[1].range_2.clone()),
+            (0..7, 14..21)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_scanner_matches_alignment() {
+        let gaps = [
+            GapPenalty::Linear(4),
+            GapPenalty::Affine {
+                open: 11,
+                extend: 1,
+            },
+            GapPenalty::Constant(6),
+        ];
+        for gap in gaps {
+            let aligner = Aligner::new(Blosum62, gap);
+            let local = aligner.clone().with_mode(Mode::Local);
+            for seed in 0..20 {
+                let query = random_protein(seed, seed as usize * 3);
+                let target = random_protein(seed + 100, 40 - seed as usize);
+                let mut scanner = aligner.local_scanner(&query);
+                assert_eq!(
+                    scanner.score(&target),
+                    local.align(&query, &target).score,
+                    "{:?} {} {}",
+                    gap,
+                    query,
+                    target
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_scan() -> Result<(), RosalindError> {
+        let records = read_records::<AminoAcid, _>(
+            [">a", "MEANLY", ">b", "PLEASANTLYEINSTEIN", ">c", "WWW"].into_iter(),
+        )?;
+        let query: Strand<AminoAcid> = "MEANLYPRTEINSTRING".parse()?;
+        let aligner = Aligner::new(Blosum62, GapPenalty::Linear(5));
+        let hits: Vec<&str> = aligner
+            .local_scanner(&query)
+            .scan(&records)
+            .iter()
+            .map(|(record, _)| record.id.as_str())
+            .collect();
+        assert_eq!(hits, vec!["b", "a", "c"]);
+        Ok(())
+    }
+}
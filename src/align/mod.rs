@@ -0,0 +1,362 @@
+use crate::nucleotides::Nucleotide;
+use crate::strands::StrandSlice;
+
+mod alignment;
+mod edit;
+mod gotoh;
+mod local;
+pub mod scoring;
+
+pub use alignment::{Alignment, Operation};
+pub use edit::{banded_edit_distance, edit_distance, myers_edit_distance, overlap_edit_distance};
+pub use local::LocalScanner;
+pub use scoring::{Blosum62, MatchMismatch, Pam250, SubstitutionMatrix};
+
+/// The penalty subtracted from the score for each gap, a run of consecutive
+/// nucleotides of one strand aligned to nothing
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum GapPenalty {
+    /// each nucleotide in a gap costs the same
+    Linear(i32),
+    /// a gap of length l costs `open + (l - 1) * extend`
+    Affine { open: i32, extend: i32 },
+    /// every gap costs the same, whatever its length
+    Constant(i32),
+}
+
+impl GapPenalty {
+    /// returns the penalties for the first nucleotide of a gap and each one after
+    fn open_extend(&self) -> (i32, i32) {
+        match *self {
+            GapPenalty::Linear(penalty) => (penalty, penalty),
+            GapPenalty::Affine { open, extend } => (open, extend),
+            GapPenalty::Constant(penalty) => (penalty, 0),
+        }
+    }
+}
+
+/// Which parts of the strands an alignment must cover
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Mode {
+    /// both strands from end to end (Needleman-Wunsch)
+    Global,
+    /// the best scoring pair of substrings (Smith-Waterman)
+    Local,
+    /// both strands, without penalizing gaps before or after either strand
+    SemiGlobal,
+    /// the whole of the second strand against a substring of the first, for
+    /// finding a short strand inside a long one
+    Fitting,
+    /// a suffix of the first strand against a prefix of the second, as when
+    /// assembling overlapping reads
+    Overlap,
+}
+
+/// The ends of the strands that may be skipped without penalty
+#[derive(Copy, Clone, Debug, Default)]
+struct FreeEnds {
+    /// a prefix of the first strand
+    start_1: bool,
+    /// a prefix of the second strand
+    start_2: bool,
+    /// a suffix of the first strand
+    end_1: bool,
+    /// a suffix of the second strand
+    end_2: bool,
+}
+
+impl Mode {
+    fn free_ends(&self) -> FreeEnds {
+        match self {
+            Mode::Global | Mode::Local => FreeEnds::default(),
+            Mode::SemiGlobal => FreeEnds {
+                start_1: true,
+                start_2: true,
+                end_1: true,
+                end_2: true,
+            },
+            Mode::Fitting => FreeEnds {
+                start_1: true,
+                end_1: true,
+                ..FreeEnds::default()
+            },
+            Mode::Overlap => FreeEnds {
+                start_1: true,
+                end_2: true,
+                ..FreeEnds::default()
+            },
+        }
+    }
+}
+
+/// The best overlap of a suffix of one strand with a prefix of another
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Overlap {
+    pub score: i32,
+    /// the number of nucleotides in the overlapping suffix of the first strand
+    pub length_1: usize,
+    /// the number of nucleotides in the overlapping prefix of the second strand
+    pub length_2: usize,
+}
+
+/// Aligns pairs of strands, scoring aligned nucleotides with the substitution
+/// matrix and subtracting the gap penalty for each gap
+#[derive(Clone, Debug)]
+pub struct Aligner<M> {
+    matrix: M,
+    gap: GapPenalty,
+    mode: Mode,
+}
+
+impl<M> Aligner<M> {
+    /// an aligner for global alignments
+    pub fn new(matrix: M, gap: GapPenalty) -> Self {
+        Aligner {
+            matrix,
+            gap,
+            mode: Mode::Global,
+        }
+    }
+
+    pub fn with_mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// returns an optimal alignment of the strands. The alignment only has columns
+    /// for the aligned ranges of the strands, leaving out any free end gaps.
+    /// Ties prefer aligning nucleotides to each other, then gaps in the second strand.
+    pub fn align<'a, 'b, T>(
+        &self,
+        strand_1: impl Into<StrandSlice<'a, T>>,
+        strand_2: impl Into<StrandSlice<'b, T>>,
+    ) -> Alignment<T>
+    where
+        T: Nucleotide + 'a + 'b,
+        M: SubstitutionMatrix<T>,
+    {
+        gotoh::align(
+            strand_1.into(),
+            strand_2.into(),
+            &self.matrix,
+            self.gap,
+            self.mode,
+            &[],
+            &[],
+        )
+    }
+
+    /// returns the score and lengths of the best overlap of a suffix of the first
+    /// strand with a prefix of the second, whatever the mode of the aligner
+    pub fn overlap<'a, 'b, T>(
+        &self,
+        strand_1: impl Into<StrandSlice<'a, T>>,
+        strand_2: impl Into<StrandSlice<'b, T>>,
+    ) -> Overlap
+    where
+        T: Nucleotide + 'a + 'b,
+        M: SubstitutionMatrix<T>,
+    {
+        let alignment = gotoh::align(
+            strand_1.into(),
+            strand_2.into(),
+            &self.matrix,
+            self.gap,
+            Mode::Overlap,
+            &[],
+            &[],
+        );
+        Overlap {
+            score: alignment.score,
+            length_1: alignment.range_1.len(),
+            length_2: alignment.range_2.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::RosalindError;
+    use crate::nucleotides::dna::DNA;
+    use crate::nucleotides::protein::AminoAcid;
+    use crate::strands::Strand;
+
+    #[test]
+    fn test_global_blosum62() -> Result<(), RosalindError> {
+        let strand_1: Strand<AminoAcid> = "PLEASANTLY".parse()?;
+        let strand_2: Strand<AminoAcid> = "MEANLY".parse()?;
+        let alignment = Aligner::new(Blosum62, GapPenalty::Linear(5)).align(&strand_1, &strand_2);
+        assert_eq!(alignment.score, 8);
+        let (aligned_1, aligned_2) = alignment.aligned_strings();
+        assert_eq!(aligned_1.replace('-', ""), "PLEASANTLY");
+        assert_eq!(aligned_2.replace('-', ""), "MEANLY");
+        Ok(())
+    }
+
+    #[test]
+    fn test_global_edit_distance() -> Result<(), RosalindError> {
+        let strand_1: Strand<AminoAcid> = "PRETTY".parse()?;
+        let strand_2: Strand<AminoAcid> = "PRTTEIN".parse()?;
+        let alignment = Aligner::new(MatchMismatch::new(0, -1), GapPenalty::Linear(1))
+            .align(&strand_1, &strand_2);
+        assert_eq!(alignment.score, -4);
+        assert_eq!(alignment.edits(), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_cigar() -> Result<(), RosalindError> {
+        let strand_1: Strand<DNA> = "ACGTTACGT".parse()?;
+        let strand_2: Strand<DNA> = "ACGTACCT".parse()?;
+        let alignment = Aligner::new(MatchMismatch::new(1, -1), GapPenalty::Linear(2))
+            .align(&strand_1, &strand_2);
+        assert_eq!(alignment.to_string(), "ACGTTACGT\nACG-TACCT");
+        assert_eq!(alignment.cigar(), "3M1I5M");
+        assert_eq!(alignment.extended_cigar(), "3=1I3=1X1=");
+        assert_eq!(alignment.score, 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_global_empty() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = "ACG".parse()?;
+        let empty: Strand<DNA> = Strand::new(vec![]);
+        let alignment =
+            Aligner::new(MatchMismatch::new(1, -1), GapPenalty::Linear(2)).align(&empty, &strand);
+        assert_eq!(alignment.score, -6);
+        assert_eq!(alignment.cigar(), "3D");
+        Ok(())
+    }
+
+    #[test]
+    fn test_affine_gaps() -> Result<(), RosalindError> {
+        let strand_1: Strand<AminoAcid> = "PRTEINS".parse()?;
+        let strand_2: Strand<AminoAcid> = "PRTWPSEIN".parse()?;
+        let gap = GapPenalty::Affine {
+            open: 11,
+            extend: 1,
+        };
+        let alignment = Aligner::new(Blosum62, gap).align(&strand_1, &strand_2);
+        assert_eq!(alignment.score, 8);
+        assert_eq!(alignment.to_string(), "PRT---EINS\nPRTWPSEIN-");
+        assert_eq!(alignment.annotation(), "|||   ||| ");
+        Ok(())
+    }
+
+    #[test]
+    fn test_constant_gaps() -> Result<(), RosalindError> {
+        let strand_1: Strand<AminoAcid> = "PLEASANTLY".parse()?;
+        let strand_2: Strand<AminoAcid> = "MEANLY".parse()?;
+        let alignment = Aligner::new(Blosum62, GapPenalty::Constant(5)).align(&strand_1, &strand_2);
+        assert_eq!(alignment.score, 13);
+        Ok(())
+    }
+
+    #[test]
+    fn test_local() -> Result<(), RosalindError> {
+        let strand_1: Strand<AminoAcid> = "MEANLYPRTEINSTRING".parse()?;
+        let strand_2: Strand<AminoAcid> = "PLEASANTLYEINSTEIN".parse()?;
+        let alignment = Aligner::new(Pam250, GapPenalty::Linear(5))
+            .with_mode(Mode::Local)
+            .align(&strand_1, &strand_2);
+        assert_eq!(alignment.score, 23);
+        // the sample answer LYPRTEINSTRIN and LYEINSTEIN ties with this longer alignment
+        assert_eq!(
+            alignment.to_string(),
+            "MEANLYPRTEINSTRIN\nLEASANTLYEINSTEIN"
+        );
+        assert_eq!((alignment.range_1, alignment.range_2), (0..17, 1..18));
+        Ok(())
+    }
+
+    #[test]
+    fn test_local_without_similarity() -> Result<(), RosalindError> {
+        let strand_1: Strand<DNA> = "AAAA".parse()?;
+        let strand_2: Strand<DNA> = "CCC".parse()?;
+        let alignment = Aligner::new(MatchMismatch::new(1, -1), GapPenalty::Linear(1))
+            .with_mode(Mode::Local)
+            .align(&strand_1, &strand_2);
+        assert_eq!(alignment.score, 0);
+        assert!(alignment.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_semi_global() -> Result<(), RosalindError> {
+        let strand_1: Strand<DNA> = "CAGCACTTGGATTCTCGG".parse()?;
+        let strand_2: Strand<DNA> = "CAGCGTGG".parse()?;
+        let alignment = Aligner::new(MatchMismatch::new(1, -1), GapPenalty::Linear(1))
+            .with_mode(Mode::SemiGlobal)
+            .align(&strand_1, &strand_2);
+        assert_eq!(alignment.score, 4);
+        assert_eq!(alignment.range_2, 0..8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fitting() -> Result<(), RosalindError> {
+        let strand_1: Strand<DNA> = "GCAAACCATAAGCCCTACGTGCCGCCTGTTTAAACTCGCGAACTGAATCTTCTGCTTCACGGTGAAAGTACCACAATGGTATCACACCCCAAGGAAAC".parse()?;
+        let strand_2: Strand<DNA> = "GCCGTCAGGCTGGTGTCCG".parse()?;
+        let alignment = Aligner::new(MatchMismatch::new(1, -1), GapPenalty::Linear(1))
+            .with_mode(Mode::Fitting)
+            .align(&strand_1, &strand_2);
+        assert_eq!(alignment.score, 5);
+        assert_eq!(alignment.range_2, 0..19);
+        Ok(())
+    }
+
+    #[test]
+    fn test_overlap() -> Result<(), RosalindError> {
+        let strand_1: Strand<DNA> = "CTAAGGGATTCCGGTAATTAGACAG".parse()?;
+        let strand_2: Strand<DNA> = "ATAGACCATATGTCAGTGACTGTGTAA".parse()?;
+        let aligner =
+            Aligner::new(MatchMismatch::new(1, -2), GapPenalty::Linear(2)).with_mode(Mode::Overlap);
+        let alignment = aligner.align(&strand_1, &strand_2);
+        assert_eq!(alignment.score, 1);
+        assert_eq!(alignment.range_1.end, strand_1.len());
+        assert_eq!(alignment.range_2.start, 0);
+        assert_eq!(
+            aligner.overlap(&strand_1, &strand_2),
+            Overlap {
+                score: 1,
+                length_1: alignment.range_1.len(),
+                length_2: alignment.range_2.len()
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_overlap_lengths_count_nucleotides() -> Result<(), RosalindError> {
+        let strand_1: Strand<DNA> = "TTTTACGTACGT".parse()?;
+        let strand_2: Strand<DNA> = "ACGACGTTTT".parse()?;
+        let aligner = Aligner::new(MatchMismatch::new(2, -3), GapPenalty::Linear(1));
+        // ACGTACGT against ACG-ACGT, with a gap in the second strand
+        assert_eq!(
+            aligner.overlap(&strand_1, &strand_2),
+            Overlap {
+                score: 13,
+                length_1: 8,
+                length_2: 7
+            }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_overlap() -> Result<(), RosalindError> {
+        let strand_1: Strand<DNA> = "AAAA".parse()?;
+        let strand_2: Strand<DNA> = "CCCC".parse()?;
+        let aligner = Aligner::new(MatchMismatch::new(1, -1), GapPenalty::Linear(1));
+        assert_eq!(
+            aligner.overlap(&strand_1, &strand_2),
+            Overlap {
+                score: 0,
+                length_1: 0,
+                length_2: 0
+            }
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+use crate::nucleotides::protein::AminoAcid;
+use crate::nucleotides::Nucleotide;
+
+/// Scores aligning one nucleotide against another. Higher scores are more similar.
+pub trait SubstitutionMatrix<T: Nucleotide> {
+    fn score(&self, a: &T, b: &T) -> i32;
+}
+
+/// Scores every pair of matching nucleotides the same, and every other pair the same.
+/// Ambiguity codes match any nucleotide they could stand for.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct MatchMismatch {
+    pub match_score: i32,
+    pub mismatch_score: i32,
+}
+
+impl MatchMismatch {
+    pub const fn new(match_score: i32, mismatch_score: i32) -> Self {
+        MatchMismatch {
+            match_score,
+            mismatch_score,
+        }
+    }
+}
+
+impl<T: Nucleotide> SubstitutionMatrix<T> for MatchMismatch {
+    fn score(&self, a: &T, b: &T) -> i32 {
+        if a.matches(b) {
+            self.match_score
+        } else {
+            self.mismatch_score
+        }
+    }
+}
+
+/// the BLOSUM62 matrix, for moderately diverged proteins
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Blosum62;
+
+/// the PAM250 matrix, for distantly related proteins
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Pam250;
+
+impl SubstitutionMatrix<AminoAcid> for Blosum62 {
+    fn score(&self, a: &AminoAcid, b: &AminoAcid) -> i32 {
+        BLOSUM62[table_index(a)][table_index(b)] as i32
+    }
+}
+
+impl SubstitutionMatrix<AminoAcid> for Pam250 {
+    fn score(&self, a: &AminoAcid, b: &AminoAcid) -> i32 {
+        PAM250[table_index(a)][table_index(b)] as i32
+    }
+}
+
+/// returns the row of the amino acid in the tables, which are in the NCBI order
+/// ARNDCQEGHILKMFPSTWYVBZX*. Selenocysteine is scored as cysteine and pyrrolysine
+/// as lysine, which they are derived from.
+fn table_index(amino_acid: &AminoAcid) -> usize {
+    match amino_acid {
+        AminoAcid::A => 0,
+        AminoAcid::R => 1,
+        AminoAcid::N => 2,
+        AminoAcid::D => 3,
+        AminoAcid::C | AminoAcid::U => 4,
+        AminoAcid::Q => 5,
+        AminoAcid::E => 6,
+        AminoAcid::G => 7,
+        AminoAcid::H => 8,
+        AminoAcid::I => 9,
+        AminoAcid::L => 10,
+        AminoAcid::K | AminoAcid::O => 11,
+        AminoAcid::M => 12,
+        AminoAcid::F => 13,
+        AminoAcid::P => 14,
+        AminoAcid::S => 15,
+        AminoAcid::T => 16,
+        AminoAcid::W => 17,
+        AminoAcid::Y => 18,
+        AminoAcid::V => 19,
+        AminoAcid::B => 20,
+        AminoAcid::Z => 21,
+        AminoAcid::X => 22,
+        AminoAcid::Stop => 23,
+    }
+}
+
+#[rustfmt::skip]
+const BLOSUM62: [[i8; 24]; 24] = [
+    //A   R   N   D   C   Q   E   G   H   I   L   K   M   F   P   S   T   W   Y   V   B   Z   X   *
+    [ 4, -1, -2, -2,  0, -1, -1,  0, -2, -1, -1, -1, -1, -2, -1,  1,  0, -3, -2,  0, -2, -1,  0, -4], // A
+    [-1,  5,  0, -2, -3,  1,  0, -2,  0, -3, -2,  2, -1, -3, -2, -1, -1, -3, -2, -3, -1,  0, -1, -4], // R
+    [-2,  0,  6,  1, -3,  0,  0,  0,  1, -3, -3,  0, -2, -3, -2,  1,  0, -4, -2, -3,  3,  0, -1, -4], // N
+    [-2, -2,  1,  6, -3,  0,  2, -1, -1, -3, -4, -1, -3, -3, -1,  0, -1, -4, -3, -3,  4,  1, -1, -4], // D
+    [ 0, -3, -3, -3,  9, -3, -4, -3, -3, -1, -1, -3, -1, -2, -3, -1, -1, -2, -2, -1, -3, -3, -2, -4], // C
+    [-1,  1,  0,  0, -3,  5,  2, -2,  0, -3, -2,  1,  0, -3, -1,  0, -1, -2, -1, -2,  0,  3, -1, -4], // Q
+    [-1,  0,  0,  2, -4,  2,  5, -2,  0, -3, -3,  1, -2, -3, -1,  0, -1, -3, -2, -2,  1,  4, -1, -4], // E
+    [ 0, -2,  0, -1, -3, -2, -2,  6, -2, -4, -4, -2, -3, -3, -2,  0, -2, -2, -3, -3, -1, -2, -1, -4], // G
+    [-2,  0,  1, -1, -3,  0,  0, -2,  8, -3, -3, -1, -2, -1, -2, -1, -2, -2,  2, -3,  0,  0, -1, -4], // H
+    [-1, -3, -3, -3, -1, -3, -3, -4, -3,  4,  2, -3,  1,  0, -3, -2, -1, -3, -1,  3, -3, -3, -1, -4], // I
+    [-1, -2, -3, -4, -1, -2, -3, -4, -3,  2,  4, -2,  2,  0, -3, -2, -1, -2, -1,  1, -4, -3, -1, -4], // L
+    [-1,  2,  0, -1, -3,  1,  1, -2, -1, -3, -2,  5, -1, -3, -1,  0, -1, -3, -2, -2,  0,  1, -1, -4], // K
+    [-1, -1, -2, -3, -1,  0, -2, -3, -2,  1,  2, -1,  5,  0, -2, -1, -1, -1, -1,  1, -3, -1, -1, -4], // M
+    [-2, -3, -3, -3, -2, -3, -3, -3, -1,  0,  0, -3,  0,  6, -4, -2, -2,  1,  3, -1, -3, -3, -1, -4], // F
+    [-1, -2, -2, -1, -3, -1, -1, -2, -2, -3, -3, -1, -2, -4,  7, -1, -1, -4, -3, -2, -2, -1, -2, -4], // P
+    [ 1, -1,  1,  0, -1,  0,  0,  0, -1, -2, -2,  0, -1, -2, -1,  4,  1, -3, -2, -2,  0,  0,  0, -4], // S
+    [ 0, -1,  0, -1, -1, -1, -1, -2, -2, -1, -1, -1, -1, -2, -1,  1,  5, -2, -2,  0, -1, -1,  0, -4], // T
+    [-3, -3, -4, -4, -2, -2, -3, -2, -2, -3, -2, -3, -1,  1, -4, -3, -2, 11,  2, -3, -4, -3, -2, -4], // W
+    [-2, -2, -2, -3, -2, -1, -2, -3,  2, -1, -1, -2, -1,  3, -3, -2, -2,  2,  7, -1, -3, -2, -1, -4], // Y
+    [ 0, -3, -3, -3, -1, -2, -2, -3, -3,  3,  1, -2,  1, -1, -2, -2,  0, -3, -1,  4, -3, -2, -1, -4], // V
+    [-2, -1,  3,  4, -3,  0,  1, -1,  0, -3, -4,  0, -3, -3, -2,  0, -1, -4, -3, -3,  4,  1, -1, -4], // B
+    [-1,  0,  0,  1, -3,  3,  4, -2,  0, -3, -3,  1, -1, -3, -1,  0, -1, -3, -2, -2,  1,  4, -1, -4], // Z
+    [ 0, -1, -1, -1, -2, -1, -1, -1, -1, -1, -1, -1, -1, -1, -2,  0,  0, -2, -1, -1, -1, -1, -1, -4], // X
+    [-4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4,  1], // *
+];
+
+#[rustfmt::skip]
+const PAM250: [[i8; 24]; 24] = [
+    //A   R   N   D   C   Q   E   G   H   I   L   K   M   F   P   S   T   W   Y   V   B   Z   X   *
+    [ 2, -2,  0,  0, -2,  0,  0,  1, -1, -1, -2, -1, -1, -3,  1,  1,  1, -6, -3,  0,  0,  0,  0, -8], // A
+    [-2,  6,  0, -1, -4,  1, -1, -3,  2, -2, -3,  3,  0, -4,  0,  0, -1,  2, -4, -2, -1,  0, -1, -8], // R
+    [ 0,  0,  2,  2, -4,  1,  1,  0,  2, -2, -3,  1, -2, -3,  0,  1,  0, -4, -2, -2,  2,  1,  0, -8], // N
+    [ 0, -1,  2,  4, -5,  2,  3,  1,  1, -2, -4,  0, -3, -6, -1,  0,  0, -7, -4, -2,  3,  3, -1, -8], // D
+    [-2, -4, -4, -5, 12, -5, -5, -3, -3, -2, -6, -5, -5, -4, -3,  0, -2, -8,  0, -2, -4, -5, -3, -8], // C
+    [ 0,  1,  1,  2, -5,  4,  2, -1,  3, -2, -2,  1, -1, -5,  0, -1, -1, -5, -4, -2,  1,  3, -1, -8], // Q
+    [ 0, -1,  1,  3, -5,  2,  4,  0,  1, -2, -3,  0, -2, -5, -1,  0,  0, -7, -4, -2,  3,  3, -1, -8], // E
+    [ 1, -3,  0,  1, -3, -1,  0,  5, -2, -3, -4, -2, -3, -5,  0,  1,  0, -7, -5, -1,  0,  0, -1, -8], // G
+    [-1,  2,  2,  1, -3,  3,  1, -2,  6, -2, -2,  0, -2, -2,  0, -1, -1, -3,  0, -2,  1,  2, -1, -8], // H
+    [-1, -2, -2, -2, -2, -2, -2, -3, -2,  5,  2, -2,  2,  1, -2, -1,  0, -5, -1,  4, -2, -2, -1, -8], // I
+    [-2, -3, -3, -4, -6, -2, -3, -4, -2,  2,  6, -3,  4,  2, -3, -3, -2, -2, -1,  2, -3, -3, -1, -8], // L
+    [-1,  3,  1,  0, -5,  1,  0, -2,  0, -2, -3,  5,  0, -5, -1,  0,  0, -3, -4, -2,  1,  0, -1, -8], // K
+    [-1,  0, -2, -3, -5, -1, -2, -3, -2,  2,  4,  0,  6,  0, -2, -2, -1, -4, -2,  2, -2, -2, -1, -8], // M
+    [-3, -4, -3, -6, -4, -5, -5, -5, -2,  1,  2, -5,  0,  9, -5, -3, -3,  0,  7, -1, -4, -5, -2, -8], // F
+    [ 1,  0,  0, -1, -3,  0, -1,  0,  0, -2, -3, -1, -2, -5,  6,  1,  0, -6, -5, -1, -1,  0, -1, -8], // P
+    [ 1,  0,  1,  0,  0, -1,  0,  1, -1, -1, -3,  0, -2, -3,  1,  2,  1, -2, -3, -1,  0,  0,  0, -8], // S
+    [ 1, -1,  0,  0, -2, -1,  0,  0, -1,  0, -2,  0, -1, -3,  0,  1,  3, -5, -3,  0,  0, -1,  0, -8], // T
+    [-6,  2, -4, -7, -8, -5, -7, -7, -3, -5, -2, -3, -4,  0, -6, -2, -5, 17,  0, -6, -5, -6, -4, -8], // W
+    [-3, -4, -2, -4,  0, -4, -4, -5,  0, -1, -1, -4, -2,  7, -5, -3, -3,  0, 10, -2, -3, -4, -2, -8], // Y
+    [ 0, -2, -2, -2, -2, -2, -2, -1, -2,  4,  2, -2,  2, -1, -1, -1,  0, -6, -2,  4, -2, -2, -1, -8], // V
+    [ 0, -1,  2,  3, -4,  1,  3,  0,  1, -2, -3,  1, -2, -4, -1,  0,  0, -5, -3, -2,  3,  2, -1, -8], // B
+    [ 0,  0,  1,  3, -5,  3,  3,  0,  2, -2, -3,  0, -2, -5,  0,  0, -1, -6, -4, -2,  2,  3, -1, -8], // Z
+    [ 0, -1,  0, -1, -3, -1, -1, -1, -1, -1, -1, -1, -1, -2, -1,  0,  0, -4, -2, -1, -1, -1, -1, -8], // X
+    [-8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8,  1], // *
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tables_are_symmetric() {
+        for table in [&BLOSUM62, &PAM250] {
+            for (i, row) in table.iter().enumerate() {
+                for (j, score) in row.iter().enumerate() {
+                    assert_eq!(*score, table[j][i], "({}, {})", i, j);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_scores() {
+        assert_eq!(Blosum62.score(&AminoAcid::W, &AminoAcid::W), 11);
+        assert_eq!(Blosum62.score(&AminoAcid::E, &AminoAcid::Z), 4);
+        assert_eq!(Blosum62.score(&AminoAcid::U, &AminoAcid::C), 9);
+        assert_eq!(Pam250.score(&AminoAcid::W, &AminoAcid::C), -8);
+        assert_eq!(Pam250.score(&AminoAcid::Y, &AminoAcid::F), 7);
+        let unit = MatchMismatch::new(1, -1);
+        assert_eq!(unit.score(&AminoAcid::B, &AminoAcid::N), 1);
+        assert_eq!(unit.score(&AminoAcid::A, &AminoAcid::N), -1);
+    }
+}
@@ -1,7 +1,10 @@
+use crate::compression;
+use crate::error::RosalindError;
+use crate::fasta::FastaReader;
+use crate::nucleotides::genetic_code::GeneticCode;
 use crate::nucleotides::Nucleotide;
 use crate::strands::Strand;
 use clap::Parser;
-use std::fs::File;
 use std::io::{self, BufRead};
 
 #[derive(Parser, Debug)]
@@ -11,6 +14,22 @@ pub struct StrandOnlyArgs {
     pub strand: String,
 }
 
+#[derive(Parser, Debug)]
+#[clap()]
+pub struct TranslationArgs {
+    #[clap()]
+    pub strand: String,
+    /// the id of the NCBI translation table to use
+    #[clap(short, long, default_value = "1")]
+    pub table: u8,
+}
+
+impl TranslationArgs {
+    pub fn genetic_code(&self) -> Result<&'static GeneticCode, RosalindError> {
+        GeneticCode::from_id(self.table).ok_or(RosalindError::UnknownGeneticCode { id: self.table })
+    }
+}
+
 #[derive(Parser, Debug)]
 #[clap()]
 pub struct TwoStrandsArgs {
@@ -21,9 +40,7 @@ pub struct TwoStrandsArgs {
 }
 
 impl TwoStrandsArgs {
-    pub fn parse_strands<T>(
-        &self,
-    ) -> Result<(Strand<T>, Strand<T>), <Strand<T> as std::str::FromStr>::Err>
+    pub fn parse_strands<T>(&self) -> Result<(Strand<T>, Strand<T>), RosalindError>
     where
         T: Nucleotide,
     {
@@ -41,8 +58,30 @@ pub struct FileArgs {
 }
 
 impl FileArgs {
-    pub fn read_file(&self) -> io::Result<io::Lines<io::BufReader<File>>> {
-        let file = File::open(&self.filename)?;
-        Ok(io::BufReader::new(file).lines())
+    /// opens the file, transparently decompressing gzip and BGZF files
+    pub fn open_file(&self) -> io::Result<Box<dyn BufRead>> {
+        compression::open(&self.filename)
     }
+
+    pub fn read_file(&self) -> io::Result<io::Lines<Box<dyn BufRead>>> {
+        Ok(self.open_file()?.lines())
+    }
+
+    /// lazily reads the file as FASTA records
+    pub fn read_fasta<T: Nucleotide>(&self) -> io::Result<FastaReader<Box<dyn BufRead>, T>> {
+        Ok(FastaReader::new(self.open_file()?))
+    }
+}
+
+#[derive(Parser, Debug)]
+#[clap()]
+pub struct OverlapArgs {
+    #[clap(flatten)]
+    pub file: FileArgs,
+    /// the length of the overlap, as the number of nucleotides of the second strand
+    #[clap(short, long, default_value = "3")]
+    pub length: usize,
+    /// the number of substitutions, insertions and deletions allowed in an overlap
+    #[clap(short, long, default_value = "0")]
+    pub errors: usize,
 }
@@ -1,10 +1,10 @@
 use clap::Parser;
-use rusty_rosalind::{args::FileArgs as Args, matrix::Matrix, nucleotides::dna::DNA};
+use rusty_rosalind::{args::FileArgs as Args, matrix::Profile, nucleotides::dna::DNA};
 
 fn main() {
     let args = Args::parse();
-    let lines = args.read_file().unwrap();
-    let records: Matrix<DNA> = Matrix::new_from_file_lines(lines);
-    println!("{}", records.consensus());
-    println!("{}", records.profile());
+    let file = args.open_file().unwrap();
+    let profile: Profile<DNA> = Profile::from_reader(file).unwrap();
+    println!("{}", profile.consensus());
+    println!("{}", profile);
 }
@@ -0,0 +1,21 @@
+use clap::Parser;
+use rusty_rosalind::args::FileArgs as Args;
+use rusty_rosalind::spectrum::{max_convolution, DEFAULT_TOLERANCE};
+
+fn main() {
+  let args = Args::parse();
+  let spectra: Vec<Vec<f64>> = args
+    .read_file()
+    .unwrap()
+    .map(|line| {
+      line
+        .unwrap()
+        .split_whitespace()
+        .map(|mass| mass.parse().unwrap())
+        .collect()
+    })
+    .collect();
+  let (multiplicity, shift) = max_convolution(&spectra[0], &spectra[1], DEFAULT_TOLERANCE);
+  println!("{}", multiplicity);
+  println!("{:.5}", shift.abs());
+}
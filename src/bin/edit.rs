@@ -0,0 +1,16 @@
+use clap::Parser;
+use rusty_rosalind::align::myers_edit_distance;
+use rusty_rosalind::args::FileArgs as Args;
+use rusty_rosalind::fasta::Fasta;
+use rusty_rosalind::nucleotides::protein::AminoAcid;
+
+fn main() {
+  let args = Args::parse();
+  let records: Vec<Fasta<AminoAcid>> = args
+    .read_fasta()
+    .unwrap()
+    .collect::<Result<_, _>>()
+    .unwrap();
+
+  println!("{}", myers_edit_distance(&records[0].strand, &records[1].strand));
+}
@@ -0,0 +1,19 @@
+use clap::Parser;
+use rusty_rosalind::align::{Aligner, GapPenalty, MatchMismatch};
+use rusty_rosalind::args::FileArgs as Args;
+use rusty_rosalind::fasta::Fasta;
+use rusty_rosalind::nucleotides::protein::AminoAcid;
+
+fn main() {
+  let args = Args::parse();
+  let records: Vec<Fasta<AminoAcid>> = args
+    .read_fasta()
+    .unwrap()
+    .collect::<Result<_, _>>()
+    .unwrap();
+
+  let alignment =
+    Aligner::new(MatchMismatch::new(0, -1), GapPenalty::Linear(1)).align(&records[0].strand, &records[1].strand);
+  println!("{}", -alignment.score);
+  println!("{}", alignment);
+}
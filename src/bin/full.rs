@@ -0,0 +1,15 @@
+use clap::Parser;
+use rusty_rosalind::args::FileArgs as Args;
+use rusty_rosalind::spectrum::{protein_from_ions, DEFAULT_TOLERANCE};
+
+fn main() {
+  let args = Args::parse();
+  // the first line is the parent mass, which is not needed
+  let ions: Vec<f64> = args
+    .read_file()
+    .unwrap()
+    .skip(1)
+    .map(|line| line.unwrap().trim().parse().unwrap())
+    .collect();
+  println!("{}", protein_from_ions(&ions, DEFAULT_TOLERANCE).unwrap());
+}
@@ -0,0 +1,18 @@
+use clap::Parser;
+use rusty_rosalind::align::{Aligner, GapPenalty, Blosum62};
+use rusty_rosalind::args::FileArgs as Args;
+use rusty_rosalind::fasta::Fasta;
+use rusty_rosalind::nucleotides::protein::AminoAcid;
+
+fn main() {
+  let args = Args::parse();
+  let records: Vec<Fasta<AminoAcid>> = args
+    .read_fasta()
+    .unwrap()
+    .collect::<Result<_, _>>()
+    .unwrap();
+
+  let alignment =
+    Aligner::new(Blosum62, GapPenalty::Constant(5)).align(&records[0].strand, &records[1].strand);
+  println!("{}", alignment.score);
+}
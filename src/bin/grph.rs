@@ -1,21 +1,35 @@
 use clap::Parser;
-use rusty_rosalind::args::FileArgs as Args;
-use rusty_rosalind::fasta::{self, Fasta};
+use rusty_rosalind::align::overlap_edit_distance;
+use rusty_rosalind::args::OverlapArgs as Args;
+use rusty_rosalind::fasta::Fasta;
 use rusty_rosalind::nucleotides::dna::DNA;
+use rusty_rosalind::strands::Strand;
 
 fn main() {
   let args = Args::parse();
-  let records: Vec<Fasta<DNA>> = fasta::read_records_from_result_lines(args.read_file().unwrap());
+  let records: Vec<Fasta<DNA>> = args
+    .file
+    .read_fasta()
+    .unwrap()
+    .collect::<Result<_, _>>()
+    .unwrap();
+  // the prefix of `length` nucleotides of the other strand must match a suffix of
+  // the current one with at most `errors` edits
+  let overlaps = |current: &Strand<DNA>, other: &Strand<DNA>| {
+    if args.errors == 0 {
+      return current.overlaps_with_length(other, args.length);
+    }
+    args.length > 0
+      && overlap_edit_distance(current, other, args.length).is_some_and(|edits| edits <= args.errors)
+  };
 
-  for i in 0..records.len() {
-    let current = &records[i];
-    for j in 0..records.len() {
+  for (i, current) in records.iter().enumerate() {
+    for (j, other) in records.iter().enumerate() {
       if i == j {
         continue;
       }
-      let other = &records[j];
-      if current.strand.overlaps_with_length(&other.strand, 3) {
-        println!("{} {}", current.name, other.name);
+      if overlaps(&current.strand, &other.strand) {
+        println!("{} {}", current.id, other.id);
       }
     }
   }
@@ -22,7 +22,7 @@ const NUM_OFFSPRING: usize = 2;
 
 fn main() {
   let args = Args::parse();
-  let populations = vec![
+  let populations = [
     args.dominant_dominant,
     args.dominant_hetero,
     args.dominant_recessive,
@@ -30,7 +30,7 @@ fn main() {
     args.hetero_recessive,
     args.recessive_recessive,
   ];
-  let events = vec![
+  let events = [
     // AA-AA
     PhenotypeCombinator::new(Phenotype::HomozygousDominant, Phenotype::HomozygousDominant),
     // AA-Aa
@@ -0,0 +1,18 @@
+use clap::Parser;
+use rusty_rosalind::args::FileArgs as Args;
+use rusty_rosalind::fasta::Fasta;
+use rusty_rosalind::nucleotides::dna::DNA;
+use rusty_rosalind::suffix_array::longest_common_substrings;
+
+fn main() {
+  let args = Args::parse();
+  let records: Vec<Fasta<DNA>> = args
+    .read_fasta()
+    .unwrap()
+    .collect::<Result<_, _>>()
+    .unwrap();
+
+  if let Some(substring) = longest_common_substrings(&records).first() {
+    println!("{}", substring);
+  }
+}
@@ -0,0 +1,16 @@
+use clap::Parser;
+use rusty_rosalind::args::FileArgs as Args;
+use rusty_rosalind::fasta::Fasta;
+use rusty_rosalind::nucleotides::dna::DNA;
+use rusty_rosalind::subsequence::longest_common_subsequence;
+
+fn main() {
+  let args = Args::parse();
+  let records: Vec<Fasta<DNA>> = args
+    .read_fasta()
+    .unwrap()
+    .collect::<Result<_, _>>()
+    .unwrap();
+
+  println!("{}", longest_common_subsequence(&records[0].strand, &records[1].strand));
+}
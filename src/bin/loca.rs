@@ -0,0 +1,22 @@
+use clap::Parser;
+use rusty_rosalind::align::{Aligner, GapPenalty, Mode, Pam250};
+use rusty_rosalind::args::FileArgs as Args;
+use rusty_rosalind::fasta::Fasta;
+use rusty_rosalind::nucleotides::protein::AminoAcid;
+
+fn main() {
+  let args = Args::parse();
+  let records: Vec<Fasta<AminoAcid>> = args
+    .read_fasta()
+    .unwrap()
+    .collect::<Result<_, _>>()
+    .unwrap();
+
+  let (strand_1, strand_2) = (&records[0].strand, &records[1].strand);
+  let alignment = Aligner::new(Pam250, GapPenalty::Linear(5))
+    .with_mode(Mode::Local)
+    .align(strand_1, strand_2);
+  println!("{}", alignment.score);
+  println!("{}", strand_1.slice(alignment.range_1));
+  println!("{}", strand_2.slice(alignment.range_2));
+}
@@ -18,7 +18,7 @@ async fn main() {
     for result in results {
         if let Ok(r) = result.unwrap() {
             let (name, matches) = r;
-            if matches.len() > 0 {
+            if !matches.is_empty() {
                 println!("{}", name);
                 println!("{:?}", matches);
             }
@@ -33,7 +33,7 @@ async fn process_id(id: String) -> Result<(String, Vec<usize>), Box<dyn Error +
         .await?;
     println!("{}", response);
     let record: Fasta<AminoAcid> =
-        Fasta::from(response.split("\n").filter(|l| l.chars().count() > 0));
+        Fasta::from_lines(response.split('\n').filter(|l| !l.is_empty()))?;
     lazy_static! {
         static ref M: Motif = Motif::new("N{P}[ST]{P}").unwrap();
     }
@@ -0,0 +1,18 @@
+use clap::Parser;
+use rusty_rosalind::args::FileArgs as Args;
+use rusty_rosalind::nucleotides::dna::DNA;
+use rusty_rosalind::strands::translation::OrfOptions;
+use std::collections::HashSet;
+
+fn main() {
+  let args = Args::parse();
+  let record = args.read_fasta::<DNA>().unwrap().next().unwrap().unwrap();
+
+  let mut proteins = HashSet::new();
+  for orf in record.strand.open_reading_frames(&OrfOptions::default()) {
+    let protein = orf.protein.to_string();
+    if proteins.insert(protein.clone()) {
+      println!("{}", protein);
+    }
+  }
+}
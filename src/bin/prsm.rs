@@ -0,0 +1,20 @@
+use clap::Parser;
+use rusty_rosalind::args::FileArgs as Args;
+use rusty_rosalind::nucleotides::protein::AminoAcid;
+use rusty_rosalind::spectrum::{match_spectrum, DEFAULT_TOLERANCE};
+use rusty_rosalind::strands::Strand;
+
+fn main() {
+  let args = Args::parse();
+  let mut lines = args.read_file().unwrap().map(|line| line.unwrap());
+  let count: usize = lines.next().unwrap().trim().parse().unwrap();
+  let candidates: Vec<Strand<AminoAcid>> = (&mut lines)
+    .take(count)
+    .map(|line| line.trim().parse().unwrap())
+    .collect();
+  let spectrum: Vec<f64> = lines.map(|line| line.trim().parse().unwrap()).collect();
+
+  let (multiplicity, protein) = match_spectrum(&spectrum, &candidates, DEFAULT_TOLERANCE).unwrap();
+  println!("{}", multiplicity);
+  println!("{}", protein);
+}
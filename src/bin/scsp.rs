@@ -0,0 +1,10 @@
+use clap::Parser;
+use rusty_rosalind::args::TwoStrandsArgs as Args;
+use rusty_rosalind::nucleotides::dna::DNA;
+use rusty_rosalind::subsequence::shortest_common_supersequence;
+
+fn main() {
+  let args = Args::parse();
+  let (strand_1, strand_2) = args.parse_strands::<DNA>().unwrap();
+  println!("{}", shortest_common_supersequence(&strand_1, &strand_2));
+}
@@ -0,0 +1,14 @@
+use clap::Parser;
+use rusty_rosalind::args::FileArgs as Args;
+use rusty_rosalind::spectrum::{protein_from_prefix_spectrum, DEFAULT_TOLERANCE};
+
+fn main() {
+  let args = Args::parse();
+  let spectrum: Vec<f64> = args
+    .read_file()
+    .unwrap()
+    .map(|line| line.unwrap().trim().parse().unwrap())
+    .collect();
+  let protein = protein_from_prefix_spectrum(&spectrum, DEFAULT_TOLERANCE).unwrap();
+  println!("{}", protein);
+}
@@ -0,0 +1,21 @@
+use clap::Parser;
+use rusty_rosalind::args::FileArgs as Args;
+use rusty_rosalind::fasta::Fasta;
+use rusty_rosalind::nucleotides::dna::DNA;
+use rusty_rosalind::subsequence::find_subsequence;
+
+fn main() {
+  let args = Args::parse();
+  let records: Vec<Fasta<DNA>> = args
+    .read_fasta()
+    .unwrap()
+    .collect::<Result<_, _>>()
+    .unwrap();
+
+  let indexes = find_subsequence(&records[1].strand, &records[0].strand)
+    .unwrap()
+    .into_iter()
+    .map(|i| (i + 1).to_string())
+    .collect::<Vec<String>>();
+  println!("{}", indexes.join(" "));
+}
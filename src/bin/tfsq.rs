@@ -0,0 +1,16 @@
+use clap::Parser;
+use rusty_rosalind::args::FileArgs as Args;
+use rusty_rosalind::fasta::{Fasta, FastaWriter};
+use rusty_rosalind::fastq::FastqReader;
+use rusty_rosalind::nucleotides::dna::DNA;
+use std::io;
+
+fn main() {
+    let args = Args::parse();
+    let records = FastqReader::<_, DNA>::new(args.open_file().unwrap());
+    let mut writer = FastaWriter::with_line_width(io::stdout().lock(), 0);
+    for record in records {
+        writer.write(&Fasta::from(record.unwrap())).unwrap();
+    }
+    writer.flush().unwrap();
+}
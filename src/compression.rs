@@ -0,0 +1,204 @@
+use flate2::bufread::MultiGzDecoder;
+use flate2::write::GzEncoder;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+
+/// The compression format of an input
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Compression {
+    None,
+    Gzip,
+    /// blocked gzip, as written by bgzip. Every BGZF file is also a valid gzip file.
+    Bgzf,
+}
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const GZIP_FLAG_EXTRA: u8 = 0x04;
+const BGZF_SUBFIELD: [u8; 2] = *b"BC";
+
+/// Detects the compression of the reader from the magic bytes at its start,
+/// without consuming any of its input.
+pub fn detect<R: BufRead>(reader: &mut R) -> io::Result<Compression> {
+    let header = reader.fill_buf()?;
+    if header.len() < 2 || header[..2] != GZIP_MAGIC {
+        return Ok(Compression::None);
+    }
+    // the BGZF block size is stored in the first extra subfield, tagged "BC"
+    if header.len() >= 14 && header[3] & GZIP_FLAG_EXTRA != 0 && header[12..14] == BGZF_SUBFIELD {
+        return Ok(Compression::Bgzf);
+    }
+    Ok(Compression::Gzip)
+}
+
+/// Wraps the reader so that gzip and BGZF compressed input is decompressed
+/// transparently. Uncompressed input is returned as is.
+pub fn decompress<'a, R: BufRead + 'a>(mut reader: R) -> io::Result<Box<dyn BufRead + 'a>> {
+    match detect(&mut reader)? {
+        Compression::None => Ok(Box::new(reader)),
+        // BGZF is a series of concatenated gzip members
+        Compression::Gzip | Compression::Bgzf => {
+            Ok(Box::new(BufReader::new(MultiGzDecoder::new(reader))))
+        }
+    }
+}
+
+/// Opens the file for reading, decompressing it if needed
+pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Box<dyn BufRead>> {
+    decompress(BufReader::new(File::open(path)?))
+}
+
+/// A writer that optionally gzip compresses everything written to it.
+///
+/// `finish` must be called once writing is done to write the gzip trailer.
+pub enum CompressedWriter<W: Write> {
+    Plain(W),
+    Gzip(GzEncoder<W>),
+}
+
+impl<W: Write> CompressedWriter<W> {
+    pub fn plain(writer: W) -> Self {
+        CompressedWriter::Plain(writer)
+    }
+
+    pub fn gzip(writer: W) -> Self {
+        CompressedWriter::Gzip(GzEncoder::new(writer, flate2::Compression::default()))
+    }
+
+    /// completes the compressed stream, returning the underlying writer
+    pub fn finish(self) -> io::Result<W> {
+        match self {
+            CompressedWriter::Plain(mut writer) => {
+                writer.flush()?;
+                Ok(writer)
+            }
+            CompressedWriter::Gzip(encoder) => {
+                let mut writer = encoder.finish()?;
+                writer.flush()?;
+                Ok(writer)
+            }
+        }
+    }
+}
+
+impl CompressedWriter<BufWriter<File>> {
+    /// creates the file for writing, gzip compressing it if its name ends in `.gz`
+    pub fn create<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let gzip = path.as_ref().extension().is_some_and(|e| e == "gz");
+        let file = BufWriter::new(File::create(path)?);
+        Ok(if gzip {
+            Self::gzip(file)
+        } else {
+            Self::plain(file)
+        })
+    }
+}
+
+impl<W: Write> Write for CompressedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            CompressedWriter::Plain(writer) => writer.write(buf),
+            CompressedWriter::Gzip(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            CompressedWriter::Plain(writer) => writer.flush(),
+            CompressedWriter::Gzip(encoder) => encoder.flush(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::RosalindError;
+    use crate::fasta::{Fasta, FastaReader, FastaWriter};
+    use crate::nucleotides::dna::DNA;
+    use std::io::Read;
+
+    const INPUT: &str = ">Rosalind_6404\nCCTGCGGAAG\n>Rosalind_5959\nCCATCGGTAG\n";
+
+    fn gzip(input: &[u8]) -> Vec<u8> {
+        let mut writer = CompressedWriter::gzip(vec![]);
+        writer.write_all(input).unwrap();
+        writer.finish().unwrap()
+    }
+
+    /// writes the input as a single BGZF block followed by the empty EOF block
+    fn bgzf(input: &[u8]) -> Vec<u8> {
+        let mut deflated =
+            flate2::write::DeflateEncoder::new(vec![], flate2::Compression::default());
+        deflated.write_all(input).unwrap();
+        let deflated = deflated.finish().unwrap();
+        let mut crc = flate2::Crc::new();
+        crc.update(input);
+
+        let mut block = vec![0x1f, 0x8b, 8, GZIP_FLAG_EXTRA, 0, 0, 0, 0, 0, 0xff, 6, 0];
+        block.extend_from_slice(b"BC");
+        block.extend_from_slice(&2u16.to_le_bytes());
+        block.extend_from_slice(&((deflated.len() + 25) as u16).to_le_bytes());
+        block.extend_from_slice(&deflated);
+        block.extend_from_slice(&crc.sum().to_le_bytes());
+        block.extend_from_slice(&(input.len() as u32).to_le_bytes());
+        block.extend_from_slice(&[
+            0x1f, 0x8b, 8, 4, 0, 0, 0, 0, 0, 0xff, 6, 0, 0x42, 0x43, 2, 0, 0x1b, 0, 3, 0, 0, 0, 0,
+            0, 0, 0, 0, 0,
+        ]);
+        block
+    }
+
+    fn read_all(input: &[u8]) -> String {
+        let mut output = String::new();
+        decompress(input)
+            .unwrap()
+            .read_to_string(&mut output)
+            .unwrap();
+        output
+    }
+
+    #[test]
+    fn test_detect() {
+        assert_eq!(detect(&mut INPUT.as_bytes()).unwrap(), Compression::None);
+        assert_eq!(
+            detect(&mut &gzip(INPUT.as_bytes())[..]).unwrap(),
+            Compression::Gzip
+        );
+        assert_eq!(
+            detect(&mut &bgzf(INPUT.as_bytes())[..]).unwrap(),
+            Compression::Bgzf
+        );
+    }
+
+    #[test]
+    fn test_decompress() {
+        assert_eq!(read_all(INPUT.as_bytes()), INPUT);
+        assert_eq!(read_all(&gzip(INPUT.as_bytes())), INPUT);
+        assert_eq!(read_all(&bgzf(INPUT.as_bytes())), INPUT);
+    }
+
+    #[test]
+    fn test_gzip_fasta_round_trip() -> Result<(), RosalindError> {
+        let mut writer = FastaWriter::new(CompressedWriter::gzip(vec![]));
+        for record in FastaReader::<_, DNA>::new(INPUT.as_bytes()) {
+            writer.write(&record?)?;
+        }
+        let compressed = writer.into_inner()?.finish()?;
+        let records = FastaReader::<_, DNA>::new(decompress(&compressed[..])?)
+            .collect::<Result<Vec<Fasta<DNA>>, RosalindError>>()?;
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].id, "Rosalind_5959");
+        Ok(())
+    }
+
+    #[test]
+    fn test_finish_flushes_buffered_writer() -> Result<(), RosalindError> {
+        let mut writer = CompressedWriter::gzip(BufWriter::new(vec![]));
+        writer.write_all(INPUT.as_bytes())?;
+        let buffered = writer.finish()?;
+        assert!(buffered.buffer().is_empty());
+        assert_eq!(read_all(buffered.get_ref()), INPUT);
+        Ok(())
+    }
+}
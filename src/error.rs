@@ -0,0 +1,181 @@
+use std::{error, fmt, io};
+
+/// The error type returned by the parsing and comparison operations in this crate
+#[derive(Debug)]
+pub enum RosalindError {
+    /// a character that is not part of the nucleotide alphabet was encountered.
+    /// `position` is the zero-based offset of the symbol within the parsed text, and
+    /// `line` is the one-based line number when the text was read from a file.
+    InvalidSymbol {
+        symbol: char,
+        position: usize,
+        line: Option<usize>,
+    },
+    /// a record did not begin with a header line (e.g. `>name` in FASTA)
+    MissingHeader { line: usize },
+    /// a FASTQ record did not have a `+` line between its strand and its qualities
+    MissingSeparator { line: usize },
+    /// the input ended part way through a record
+    TruncatedRecord { line: usize },
+    /// a character outside of the range of the quality encoding was encountered
+    InvalidQuality {
+        symbol: char,
+        position: usize,
+        line: usize,
+    },
+    /// the records of an alignment are not all of the same length
+    RaggedAlignment {
+        record: String,
+        expected: usize,
+        actual: usize,
+    },
+    /// two strands were expected to have the same length
+    LengthMismatch { expected: usize, actual: usize },
+    /// a strand cannot be split evenly into codons
+    IncompleteCodon { length: usize },
+    /// the lines of a FASTA record are not all the same length, so it cannot be indexed
+    InconsistentLineLength { line: usize },
+    /// a line of a FASTA index could not be parsed
+    MalformedIndex { line: usize },
+    /// the requested sequence is not in the index
+    UnknownSequence { name: String },
+    /// the region could not be parsed, or its start is after its end
+    InvalidRegion { region: String },
+    /// there is no NCBI translation table with the id
+    UnknownGeneticCode { id: u8 },
+    /// no amino acid has a mass within the tolerance of the mass
+    UnmatchedMass { mass: f64 },
+    /// a count modulo 0 was requested
+    ZeroModulus,
+    /// a mass to charge ratio was requested for a neutral ion
+    ZeroCharge,
+    /// the underlying reader failed
+    Io(io::Error),
+}
+
+impl RosalindError {
+    /// returns a copy of this error attributed to the given one-based line number.
+    /// errors that do not carry a line number are returned unchanged.
+    pub fn at_line(self, line: usize) -> Self {
+        match self {
+            RosalindError::InvalidSymbol {
+                symbol, position, ..
+            } => RosalindError::InvalidSymbol {
+                symbol,
+                position,
+                line: Some(line),
+            },
+            _ => self,
+        }
+    }
+}
+
+impl fmt::Display for RosalindError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RosalindError::InvalidSymbol {
+                symbol,
+                position,
+                line: Some(line),
+            } => write!(
+                f,
+                "invalid symbol '{}' at line {}, position {}",
+                symbol, line, position
+            ),
+            RosalindError::InvalidSymbol {
+                symbol,
+                position,
+                line: None,
+            } => write!(f, "invalid symbol '{}' at position {}", symbol, position),
+            RosalindError::MissingHeader { line } => {
+                write!(f, "expected a header at line {}", line)
+            }
+            RosalindError::MissingSeparator { line } => {
+                write!(f, "expected a '+' separator at line {}", line)
+            }
+            RosalindError::TruncatedRecord { line } => {
+                write!(f, "record ending at line {} is incomplete", line)
+            }
+            RosalindError::InvalidQuality {
+                symbol,
+                position,
+                line,
+            } => write!(
+                f,
+                "invalid quality '{}' at line {}, position {}",
+                symbol, line, position
+            ),
+            RosalindError::RaggedAlignment {
+                record,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "record {} has length {}, expected {}",
+                record, actual, expected
+            ),
+            RosalindError::LengthMismatch { expected, actual } => write!(
+                f,
+                "strands have different lengths: {} and {}",
+                expected, actual
+            ),
+            RosalindError::IncompleteCodon { length } => write!(
+                f,
+                "a strand of length {} cannot be split into codons",
+                length
+            ),
+            RosalindError::InconsistentLineLength { line } => {
+                write!(
+                    f,
+                    "line {} has a different length to the rest of its record",
+                    line
+                )
+            }
+            RosalindError::MalformedIndex { line } => {
+                write!(f, "malformed index entry at line {}", line)
+            }
+            RosalindError::UnknownSequence { name } => write!(f, "unknown sequence {}", name),
+            RosalindError::InvalidRegion { region } => write!(f, "invalid region {}", region),
+            RosalindError::UnknownGeneticCode { id } => {
+                write!(f, "unknown translation table {}", id)
+            }
+            RosalindError::UnmatchedMass { mass } => {
+                write!(f, "no amino acid has the mass {}", mass)
+            }
+            RosalindError::ZeroModulus => write!(f, "the modulus must be positive"),
+            RosalindError::ZeroCharge => write!(f, "a neutral ion has no mass to charge ratio"),
+            RosalindError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl error::Error for RosalindError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            RosalindError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for RosalindError {
+    fn from(e: io::Error) -> Self {
+        RosalindError::Io(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at_line() {
+        let e = RosalindError::InvalidSymbol {
+            symbol: 'X',
+            position: 4,
+            line: None,
+        }
+        .at_line(3);
+        assert_eq!(format!("{}", e), "invalid symbol 'X' at line 3, position 4");
+    }
+}
@@ -0,0 +1,373 @@
+use crate::error::RosalindError;
+use crate::header::split_header;
+use crate::nucleotides::Nucleotide;
+use crate::strands::Strand;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// The location of a single record within a FASTA file, as stored in a samtools `.fai` index
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FaiEntry {
+    /// the record's identifier
+    pub name: String,
+    /// the number of nucleotides in the record
+    pub length: u64,
+    /// the byte offset of the record's first nucleotide
+    pub offset: u64,
+    /// the number of nucleotides on each full line
+    pub line_bases: u64,
+    /// the number of bytes in each full line, including the line ending
+    pub line_width: u64,
+}
+
+impl FaiEntry {
+    /// returns the byte offset of the nucleotide at the zero-based position
+    fn byte_offset(&self, position: u64) -> u64 {
+        self.offset + (position / self.line_bases) * self.line_width + position % self.line_bases
+    }
+}
+
+/// A samtools compatible FASTA index
+pub struct FaiIndex {
+    entries: Vec<FaiEntry>,
+    names: HashMap<String, usize>,
+}
+
+impl FaiIndex {
+    pub fn new(entries: Vec<FaiEntry>) -> Self {
+        let names = entries
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (e.name.clone(), i))
+            .collect();
+        FaiIndex { entries, names }
+    }
+
+    /// indexes an uncompressed FASTA file. Every line of a record except the last
+    /// must contain the same number of nucleotides.
+    pub fn build<R: BufRead>(mut reader: R) -> Result<Self, RosalindError> {
+        let mut entries = vec![];
+        let mut current: Option<FaiEntry> = None;
+        // set once a record has a line shorter than the rest, which must be its last
+        let mut record_ended = false;
+        let mut offset = 0;
+        let mut line_number = 0;
+        let mut line = vec![];
+        loop {
+            line.clear();
+            let read = reader.read_until(b'\n', &mut line)? as u64;
+            if read == 0 {
+                break;
+            }
+            line_number += 1;
+            offset += read;
+            let bases = line
+                .iter()
+                .rposition(|b| *b != b'\n' && *b != b'\r')
+                .map_or(0, |i| i as u64 + 1);
+
+            if line.starts_with(b">") {
+                entries.extend(current.take());
+                let header = String::from_utf8_lossy(&line[1..bases as usize]);
+                current = Some(FaiEntry {
+                    name: split_header(&header).0,
+                    length: 0,
+                    offset,
+                    line_bases: 0,
+                    line_width: 0,
+                });
+                record_ended = false;
+                continue;
+            }
+
+            let entry = current
+                .as_mut()
+                .ok_or(RosalindError::MissingHeader { line: line_number })?;
+            if bases == 0 {
+                record_ended = true;
+                continue;
+            }
+            if record_ended || bases > entry.line_bases && entry.line_bases > 0 {
+                return Err(RosalindError::InconsistentLineLength { line: line_number });
+            }
+            if entry.line_bases == 0 {
+                entry.line_bases = bases;
+                entry.line_width = read;
+            }
+            record_ended = bases < entry.line_bases || read != entry.line_width;
+            entry.length += bases;
+        }
+        entries.extend(current);
+        Ok(FaiIndex::new(entries))
+    }
+
+    /// reads an index in the tab separated `.fai` format
+    pub fn read<R: BufRead>(reader: R) -> Result<Self, RosalindError> {
+        let mut entries = vec![];
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+            let malformed = || RosalindError::MalformedIndex { line: i + 1 };
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() < 5 {
+                return Err(malformed());
+            }
+            let number = |field: &str| field.parse::<u64>().map_err(|_| malformed());
+            let entry = FaiEntry {
+                name: fields[0].to_string(),
+                length: number(fields[1])?,
+                offset: number(fields[2])?,
+                line_bases: number(fields[3])?,
+                line_width: number(fields[4])?,
+            };
+            // a record with nucleotides needs lines to hold them, each with a line ending
+            if entry.length > 0 && (entry.line_bases == 0 || entry.line_width <= entry.line_bases) {
+                return Err(malformed());
+            }
+            entries.push(entry);
+        }
+        Ok(FaiIndex::new(entries))
+    }
+
+    /// writes the index in the tab separated `.fai` format
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<(), RosalindError> {
+        for e in &self.entries {
+            writeln!(
+                writer,
+                "{}\t{}\t{}\t{}\t{}",
+                e.name, e.length, e.offset, e.line_bases, e.line_width
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&FaiEntry> {
+        self.names.get(name).map(|i| &self.entries[*i])
+    }
+
+    pub fn entries(&self) -> &[FaiEntry] {
+        &self.entries
+    }
+}
+
+/// A region of a named sequence. Parsed from the samtools notation `name:start-end`,
+/// where the positions are one-based and inclusive.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Region {
+    pub name: String,
+    /// the zero-based position of the first nucleotide in the region
+    pub start: u64,
+    /// the zero-based position after the last nucleotide in the region, or None
+    /// if the region runs to the end of the sequence
+    pub end: Option<u64>,
+}
+
+impl FromStr for Region {
+    type Err = RosalindError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || RosalindError::InvalidRegion {
+            region: s.to_string(),
+        };
+        let position = |p: &str| match p.replace(',', "").parse::<u64>() {
+            Ok(p) if p > 0 => Ok(p),
+            _ => Err(invalid()),
+        };
+        let (name, range) = match s.rsplit_once(':') {
+            Some((name, range)) if range.starts_with(|c: char| c.is_ascii_digit()) => (name, range),
+            _ => {
+                return Ok(Region {
+                    name: s.to_string(),
+                    start: 0,
+                    end: None,
+                })
+            }
+        };
+        let (start, end) = match range.split_once('-') {
+            Some((start, end)) => (position(start)?, Some(position(end)?)),
+            None => (position(range)?, None),
+        };
+        if end.is_some_and(|end| end < start) {
+            return Err(invalid());
+        }
+        Ok(Region {
+            name: name.to_string(),
+            start: start - 1,
+            end,
+        })
+    }
+}
+
+/// Reads regions of an indexed FASTA file without reading the rest of the file
+pub struct IndexedFastaReader<R: Read + Seek> {
+    reader: R,
+    index: FaiIndex,
+}
+
+impl IndexedFastaReader<BufReader<File>> {
+    /// opens an uncompressed FASTA file, loading its index from `<path>.fai`.
+    /// if there is no index, one is built in memory by scanning the file.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, RosalindError> {
+        let mut index_path = PathBuf::from(path.as_ref()).into_os_string();
+        index_path.push(".fai");
+        let index = match File::open(&index_path) {
+            Ok(file) => FaiIndex::read(BufReader::new(file))?,
+            Err(_) => FaiIndex::build(BufReader::new(File::open(&path)?))?,
+        };
+        Ok(IndexedFastaReader::new(
+            BufReader::new(File::open(path)?),
+            index,
+        ))
+    }
+}
+
+impl<R: Read + Seek> IndexedFastaReader<R> {
+    pub fn new(reader: R, index: FaiIndex) -> Self {
+        IndexedFastaReader { reader, index }
+    }
+
+    pub fn index(&self) -> &FaiIndex {
+        &self.index
+    }
+
+    /// fetches the region, given in the samtools notation `name:start-end`
+    pub fn fetch<T: Nucleotide>(&mut self, region: &str) -> Result<Strand<T>, RosalindError> {
+        self.fetch_region(&region.parse()?)
+    }
+
+    /// fetches the nucleotides of the region. Regions extending past the end of
+    /// the sequence are truncated to it.
+    pub fn fetch_region<T: Nucleotide>(
+        &mut self,
+        region: &Region,
+    ) -> Result<Strand<T>, RosalindError> {
+        let entry = self
+            .index
+            .get(&region.name)
+            .ok_or_else(|| RosalindError::UnknownSequence {
+                name: region.name.clone(),
+            })?;
+        let end = region.end.unwrap_or(entry.length).min(entry.length);
+        if region.start >= end {
+            return Ok(Strand::new(vec![]));
+        }
+        let byte_start = entry.byte_offset(region.start);
+        let byte_end = entry.byte_offset(end - 1) + 1;
+
+        let mut buffer = vec![0; (byte_end - byte_start) as usize];
+        self.reader.seek(SeekFrom::Start(byte_start))?;
+        self.reader.read_exact(&mut buffer)?;
+        buffer.retain(|b| *b != b'\n' && *b != b'\r');
+        String::from_utf8_lossy(&buffer).parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nucleotides::dna::DNA;
+    use std::io::Cursor;
+
+    const INPUT: &str = ">chr1 first\nACGTA\nCCGTT\nGG\n>chr2\nTTTT\nAAAA\n";
+
+    fn reader() -> Result<IndexedFastaReader<Cursor<&'static str>>, RosalindError> {
+        let index = FaiIndex::build(INPUT.as_bytes())?;
+        Ok(IndexedFastaReader::new(Cursor::new(INPUT), index))
+    }
+
+    #[test]
+    fn test_build() -> Result<(), RosalindError> {
+        let index = FaiIndex::build(INPUT.as_bytes())?;
+        let mut output = vec![];
+        index.write(&mut output)?;
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "chr1\t12\t12\t5\t6\nchr2\t8\t33\t4\t5\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_round_trip() -> Result<(), RosalindError> {
+        let index = FaiIndex::read("chr1\t12\t12\t5\t6\nchr2\t8\t33\t4\t5\n".as_bytes())?;
+        assert_eq!(
+            index.entries(),
+            FaiIndex::build(INPUT.as_bytes())?.entries()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_invalid_line_lengths() {
+        for index in ["chr1\t12\t6\t0\t0\n", "chr1\t12\t6\t5\t5\n"] {
+            assert!(matches!(
+                FaiIndex::read(index.as_bytes()),
+                Err(RosalindError::MalformedIndex { line: 1 })
+            ));
+        }
+        assert!(FaiIndex::read("empty\t0\t7\t0\t0\n".as_bytes()).is_ok());
+    }
+
+    #[test]
+    fn test_build_inconsistent_lines() {
+        let result = FaiIndex::build(">chr1\nACG\nACGTA\n".as_bytes());
+        assert!(matches!(
+            result,
+            Err(RosalindError::InconsistentLineLength { line: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_fetch() -> Result<(), RosalindError> {
+        let mut reader = reader()?;
+        let expected: Strand<DNA> = "TACCGTTG".parse()?;
+        assert_eq!(reader.fetch::<DNA>("chr1:4-11")?, expected);
+        let expected: Strand<DNA> = "TTTTAAAA".parse()?;
+        assert_eq!(reader.fetch::<DNA>("chr2")?, expected);
+        let expected: Strand<DNA> = "AA".parse()?;
+        assert_eq!(reader.fetch::<DNA>("chr2:7-100")?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_unknown_sequence() -> Result<(), RosalindError> {
+        assert!(matches!(
+            reader()?.fetch::<DNA>("chr3:1-2"),
+            Err(RosalindError::UnknownSequence { .. })
+        ));
+        Ok(())
+    }
+
+    macro_rules! region_test {
+    ($($name:ident: $value:expr,)*) => {
+      $(
+        #[test]
+        fn $name() -> Result<(), RosalindError> {
+          let (region, name, start, end) = $value;
+          let expected = Region { name: name.to_string(), start, end };
+          assert_eq!(region.parse::<Region>()?, expected);
+          Ok(())
+        }
+      )*
+    };
+  }
+
+    region_test! {
+      region_name_only: ("chr1", "chr1", 0, None),
+      region_start_only: ("chr1:100", "chr1", 99, None),
+      region_start_end: ("chr1:1,000-2,000", "chr1", 999, Some(2000)),
+    }
+
+    #[test]
+    fn test_region_end_before_start() {
+        assert!(matches!(
+            "chr1:10-5".parse::<Region>(),
+            Err(RosalindError::InvalidRegion { .. })
+        ));
+    }
+}
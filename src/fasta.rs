@@ -1,82 +1,264 @@
+use super::error::RosalindError;
+use super::header::{join_header, split_header, SequenceId};
 use super::nucleotides::Nucleotide;
 use super::strands::Strand;
+use std::fmt::Write as _;
+use std::io::{BufRead, Write};
 
 /// a record in the FASTA format
 pub struct Fasta<T: Nucleotide> {
-    pub name: String,
+    /// the header up to the first whitespace
+    pub id: String,
+    /// the rest of the header, if any
+    pub description: Option<String>,
     pub strand: Strand<T>,
 }
 
 /// reads the lines into a list of FASTA records.
-/// panics if any line is not Ok
+/// fails if any line is not Ok or cannot be parsed
 pub fn read_records_from_result_lines<TNucleotide, TLines, TError>(
     lines: TLines,
-) -> Vec<Fasta<TNucleotide>>
+) -> Result<Vec<Fasta<TNucleotide>>, RosalindError>
 where
     TNucleotide: Nucleotide,
     TLines: Iterator<Item = Result<String, TError>>,
-    TError: std::fmt::Debug,
-    <TNucleotide as TryFrom<char>>::Error: std::fmt::Debug,
+    TError: Into<RosalindError>,
 {
-    let unwrapped_lines = lines
-        .into_iter()
-        .map(|l| l.unwrap())
-        .collect::<Vec<String>>();
-    read_records(unwrapped_lines.iter().map(|l| &l[..]))
+    let mut parser = RecordParser::new();
+    let mut records: Vec<Fasta<TNucleotide>> = vec![];
+    for line in lines {
+        records.extend(parser.push(&line.map_err(Into::into)?)?);
+    }
+    records.extend(parser.finish());
+    Ok(records)
 }
 
 /// reads the lines into a list of FASTA records
-pub fn read_records<'a, TNucleotide, TLines>(lines: TLines) -> Vec<Fasta<TNucleotide>>
+pub fn read_records<'a, TNucleotide, TLines>(
+    lines: TLines,
+) -> Result<Vec<Fasta<TNucleotide>>, RosalindError>
 where
     TNucleotide: Nucleotide,
     TLines: Iterator<Item = &'a str>,
-    <TNucleotide as TryFrom<char>>::Error: std::fmt::Debug,
 {
+    let mut parser = RecordParser::new();
     let mut records: Vec<Fasta<TNucleotide>> = vec![];
-    let mut current_lines: Vec<&str> = vec![];
     for line in lines {
-        if line.starts_with(">") {
-            if !current_lines.is_empty() {
-                records.push(Fasta::from(current_lines.into_iter()));
-            }
-            current_lines = vec![];
+        records.extend(parser.push(line)?);
+    }
+    records.extend(parser.finish());
+    Ok(records)
+}
+
+/// Lazily reads FASTA records from a buffered reader. Only the record currently
+/// being parsed is held in memory, so arbitrarily large files can be processed.
+///
+/// The iterator stops after the first error it returns.
+pub struct FastaReader<R: BufRead, T: Nucleotide> {
+    reader: R,
+    line: String,
+    parser: RecordParser<T>,
+    done: bool,
+}
+
+impl<R: BufRead, T: Nucleotide> FastaReader<R, T> {
+    pub fn new(reader: R) -> Self {
+        FastaReader {
+            reader,
+            line: String::new(),
+            parser: RecordParser::new(),
+            done: false,
         }
-        current_lines.push(line);
     }
-    if !current_lines.is_empty() {
-        records.push(Fasta::from(current_lines.into_iter()));
+}
+
+impl<R: BufRead, T: Nucleotide> Iterator for FastaReader<R, T> {
+    type Item = Result<Fasta<T>, RosalindError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.line.clear();
+            let result = match self.reader.read_line(&mut self.line) {
+                Ok(0) => {
+                    self.done = true;
+                    return self.parser.finish().map(Ok);
+                }
+                Ok(_) => self.parser.push(&self.line),
+                Err(e) => Err(RosalindError::from(e)),
+            };
+            match result {
+                Ok(None) => continue,
+                Ok(Some(record)) => return Some(Ok(record)),
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+        None
     }
-    records
 }
 
-impl<'a, TNucleotide, TIterator> From<TIterator> for Fasta<TNucleotide>
-where
-    TNucleotide: Nucleotide,
-    TIterator: IntoIterator<Item = &'a str>,
-    <TNucleotide as TryFrom<char>>::Error: std::fmt::Debug,
-{
-    fn from(lines: TIterator) -> Self {
-        let mut lines_iter = lines.into_iter();
-        let first_line = lines_iter.next().unwrap();
-        Fasta {
-            name: parse_name(first_line).to_string(),
-            strand: lines_iter.collect::<Vec<&str>>().join("").parse().unwrap(),
+/// Builds FASTA records from lines fed to it one at a time
+struct RecordParser<T: Nucleotide> {
+    current: Option<Fasta<T>>,
+    line_number: usize,
+}
+
+impl<T: Nucleotide> RecordParser<T> {
+    fn new() -> Self {
+        RecordParser {
+            current: None,
+            line_number: 0,
         }
     }
+
+    /// parses the next line, returning the previous record if this line starts a new one
+    fn push(&mut self, line: &str) -> Result<Option<Fasta<T>>, RosalindError> {
+        self.line_number += 1;
+        let line = line.trim_end();
+        if line.is_empty() {
+            return Ok(None);
+        }
+        if line.starts_with('>') {
+            let previous = self.current.take();
+            self.current = Some(Fasta::from_header(line, self.line_number)?);
+            return Ok(previous);
+        }
+        match self.current.as_mut() {
+            Some(record) => record.push_line(line, self.line_number)?,
+            None => {
+                return Err(RosalindError::MissingHeader {
+                    line: self.line_number,
+                })
+            }
+        }
+        Ok(None)
+    }
+
+    /// returns the record currently being parsed, if any
+    fn finish(&mut self) -> Option<Fasta<T>> {
+        self.current.take()
+    }
 }
 
 impl<T: Nucleotide> Fasta<T> {
+    /// parses a single FASTA record from its lines. The first line must be the header.
+    pub fn from_lines<'a, TIterator>(lines: TIterator) -> Result<Self, RosalindError>
+    where
+        TIterator: IntoIterator<Item = &'a str>,
+    {
+        let mut lines_iter = lines.into_iter();
+        let mut record = Fasta::from_header(lines_iter.next().unwrap_or(""), 1)?;
+        for (i, line) in lines_iter.enumerate() {
+            record.push_line(line.trim_end(), i + 2)?;
+        }
+        Ok(record)
+    }
+
     /// Returns the number of nucleotides in the strand, also referred to as its 'length'.
     pub fn len(&self) -> usize {
         self.strand.len()
     }
+
+    /// Returns true if the record's strand contains no nucleotides.
+    pub fn is_empty(&self) -> bool {
+        self.strand.is_empty()
+    }
+
+    /// parses the structure of the record's identifier
+    pub fn sequence_id(&self) -> SequenceId<'_> {
+        SequenceId::parse(&self.id)
+    }
+
+    /// returns the full text of the header, without the leading `>`
+    pub fn header(&self) -> String {
+        join_header(&self.id, self.description.as_deref())
+    }
+
+    fn from_header(line: &str, line_number: usize) -> Result<Self, RosalindError> {
+        let (id, description) = split_header(parse_name(line, line_number)?);
+        Ok(Fasta {
+            id,
+            description,
+            strand: Strand::new(vec![]),
+        })
+    }
+
+    fn push_line(&mut self, line: &str, line_number: usize) -> Result<(), RosalindError> {
+        for (position, c) in line.chars().enumerate() {
+            self.strand
+                .push_char(c)
+                .map_err(|symbol| RosalindError::InvalidSymbol {
+                    symbol,
+                    position,
+                    line: Some(line_number),
+                })?;
+        }
+        Ok(())
+    }
 }
 
-fn parse_name(line: &str) -> &str {
-    if !line.starts_with(">") {
-        panic!();
+fn parse_name(line: &str, line_number: usize) -> Result<&str, RosalindError> {
+    line.strip_prefix('>')
+        .ok_or(RosalindError::MissingHeader { line: line_number })
+}
+
+/// the line width used by most FASTA tools
+pub const STANDARD_LINE_WIDTH: usize = 60;
+
+/// the line width used by NCBI when serving FASTA files
+pub const NCBI_LINE_WIDTH: usize = 70;
+
+/// Writes FASTA records, wrapping each strand across lines of a fixed width.
+pub struct FastaWriter<W: Write> {
+    writer: W,
+    line_width: usize,
+    buffer: String,
+}
+
+impl<W: Write> FastaWriter<W> {
+    /// creates a writer that wraps strands at STANDARD_LINE_WIDTH
+    pub fn new(writer: W) -> Self {
+        Self::with_line_width(writer, STANDARD_LINE_WIDTH)
+    }
+
+    /// creates a writer that wraps strands at the given width.
+    /// a width of 0 writes each strand on a single line.
+    pub fn with_line_width(writer: W, line_width: usize) -> Self {
+        FastaWriter {
+            writer,
+            line_width,
+            buffer: String::new(),
+        }
+    }
+
+    /// writes the record's header and its wrapped strand
+    pub fn write<T: Nucleotide>(&mut self, record: &Fasta<T>) -> Result<(), RosalindError> {
+        writeln!(self.writer, ">{}", record.header())?;
+        self.buffer.clear();
+        write!(self.buffer, "{}", record.strand).expect("writing to a String cannot fail");
+        let width = if self.line_width == 0 {
+            self.buffer.len().max(1)
+        } else {
+            self.line_width
+        };
+        for line in self.buffer.as_bytes().chunks(width) {
+            self.writer.write_all(line)?;
+            self.writer.write_all(b"\n")?;
+        }
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<(), RosalindError> {
+        Ok(self.writer.flush()?)
+    }
+
+    /// flushes and returns the underlying writer
+    pub fn into_inner(mut self) -> Result<W, RosalindError> {
+        self.flush()?;
+        Ok(self.writer)
     }
-    &line[1..]
 }
 
 #[cfg(test)]
@@ -85,21 +267,22 @@ mod tests {
     use crate::nucleotides::dna::DNA;
 
     #[test]
-    fn test_from() {
-        let result: Fasta<DNA> = Fasta::from(vec![
+    fn test_from() -> Result<(), RosalindError> {
+        let result: Fasta<DNA> = Fasta::from_lines(vec![
             ">Rosalind_6404",
             "CCTGCGGAAGATCGGCACTAGAATAGCCAGAACCGTTTCTCTGAGGCTTCCGGCCTTCCC",
             "TCCCACTAATAATTCTGAGG",
-        ]);
-        assert_eq!(result.name, "Rosalind_6404");
+        ])?;
+        assert_eq!(result.id, "Rosalind_6404");
         assert_eq!(
             format!("{}", result.strand),
             "CCTGCGGAAGATCGGCACTAGAATAGCCAGAACCGTTTCTCTGAGGCTTCCGGCCTTCCCTCCCACTAATAATTCTGAGG"
         );
+        Ok(())
     }
 
     #[test]
-    fn test_read_records() {
+    fn test_read_records() -> Result<(), RosalindError> {
         let records: Vec<Fasta<DNA>> = read_records(
             vec![
                 ">Rosalind_6404",
@@ -113,8 +296,110 @@ mod tests {
                 "TGGGAACCTGCGGGCAGTAGGTGGAAT",
             ]
             .into_iter(),
-        );
+        )?;
         assert_eq!(records.len(), 3);
-        assert_eq!(records[0].name, "Rosalind_6404");
+        assert_eq!(records[0].id, "Rosalind_6404");
+        Ok(())
+    }
+
+    #[test]
+    fn test_fasta_reader() -> Result<(), RosalindError> {
+        let input = ">Rosalind_6404\nCCTGCGGAAG\nATCGG\n\n>Rosalind_5959\nCCATCGGTAG\n";
+        let records = FastaReader::<_, DNA>::new(input.as_bytes())
+            .collect::<Result<Vec<Fasta<DNA>>, RosalindError>>()?;
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].id, "Rosalind_6404");
+        assert_eq!(format!("{}", records[0].strand), "CCTGCGGAAGATCGG");
+        assert_eq!(records[1].id, "Rosalind_5959");
+        assert_eq!(format!("{}", records[1].strand), "CCATCGGTAG");
+        Ok(())
+    }
+
+    #[test]
+    fn test_fasta_reader_stops_after_error() {
+        let input = ">Rosalind_6404\nCCTG\n>Rosalind_5959\nCCAU\n>Rosalind_0808\nCCAC\n";
+        let mut reader = FastaReader::<_, DNA>::new(input.as_bytes());
+        assert!(matches!(reader.next(), Some(Ok(_))));
+        assert!(matches!(
+            reader.next(),
+            Some(Err(RosalindError::InvalidSymbol {
+                symbol: 'U',
+                position: 3,
+                line: Some(4)
+            }))
+        ));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_header_description() -> Result<(), RosalindError> {
+        let record: Fasta<DNA> = Fasta::from_lines(vec![
+            ">sp|P07204|TRBM_HUMAN Thrombomodulin OS=Homo sapiens",
+            "ACGT",
+        ])?;
+        assert_eq!(record.id, "sp|P07204|TRBM_HUMAN");
+        assert_eq!(
+            record.description.as_deref(),
+            Some("Thrombomodulin OS=Homo sapiens")
+        );
+        assert_eq!(record.sequence_id().accession(), "P07204");
+        Ok(())
+    }
+
+    #[test]
+    fn test_fasta_writer_keeps_soft_mask() -> Result<(), RosalindError> {
+        let input = ">chr1\nACGTacgtAC\ngtACGT\n";
+        let mut writer = FastaWriter::with_line_width(vec![], 10);
+        for record in FastaReader::<_, DNA>::new(input.as_bytes()) {
+            writer.write(&record?)?;
+        }
+        assert_eq!(String::from_utf8(writer.into_inner()?).unwrap(), input);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fasta_writer_wraps_lines() -> Result<(), RosalindError> {
+        let record: Fasta<DNA> = Fasta::from_lines(vec![">Rosalind_6404", "CCTGCGGAAGATCG"])?;
+        let mut writer = FastaWriter::with_line_width(vec![], 5);
+        writer.write(&record)?;
+        let output = String::from_utf8(writer.into_inner()?).unwrap();
+        assert_eq!(output, ">Rosalind_6404\nCCTGC\nGGAAG\nATCG\n");
+        Ok(())
+    }
+
+    #[test]
+    fn test_fasta_writer_round_trip() -> Result<(), RosalindError> {
+        let input = ">Rosalind_6404 some description\nCCTGCGGAAGATCGGCACTAGAATAGCCAGAACCGTTTCTCTGAGGCTTCCGGCCTTCCC\nTCCCACTAATAATTCTGAGG\n>Rosalind_5959\nCCATCGGTAG\n";
+        let mut writer = FastaWriter::new(vec![]);
+        for record in FastaReader::<_, DNA>::new(input.as_bytes()) {
+            writer.write(&record?)?;
+        }
+        let output = String::from_utf8(writer.into_inner()?).unwrap();
+        assert_eq!(output, input);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_records_missing_header() {
+        let result: Result<Vec<Fasta<DNA>>, RosalindError> =
+            read_records(vec!["ACGT", ">Rosalind_6404"].into_iter());
+        assert!(matches!(
+            result,
+            Err(RosalindError::MissingHeader { line: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_read_records_invalid_symbol() {
+        let result: Result<Vec<Fasta<DNA>>, RosalindError> =
+            read_records(vec![">Rosalind_6404", "ACGT", "ACXT"].into_iter());
+        assert!(matches!(
+            result,
+            Err(RosalindError::InvalidSymbol {
+                symbol: 'X',
+                position: 2,
+                line: Some(3)
+            })
+        ));
     }
 }
@@ -0,0 +1,412 @@
+use super::error::RosalindError;
+use super::fasta::Fasta;
+use super::header::{join_header, split_header, SequenceId};
+use super::nucleotides::Nucleotide;
+use super::strands::Strand;
+use std::io::{BufRead, Write};
+use std::marker::PhantomData;
+
+/// The offset used to store Phred quality scores as printable ASCII characters
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PhredEncoding {
+    /// Sanger / Illumina 1.8+ encoding, where '!' is a quality of 0
+    Phred33,
+    /// Illumina 1.3 - 1.7 encoding, where '@' is a quality of 0
+    Phred64,
+}
+
+impl PhredEncoding {
+    fn offset(&self) -> u8 {
+        match self {
+            PhredEncoding::Phred33 => 33,
+            PhredEncoding::Phred64 => 64,
+        }
+    }
+
+    /// returns the quality score encoded by the character, or None if it is
+    /// outside of the range of this encoding
+    pub fn decode(&self, c: char) -> Option<u8> {
+        match c {
+            '!'..='~' if c as u8 >= self.offset() => Some(c as u8 - self.offset()),
+            _ => None,
+        }
+    }
+
+    /// returns the character encoding the quality score. Scores too large to be
+    /// encoded are written as the highest printable character, '~'.
+    pub fn encode(&self, quality: u8) -> char {
+        quality.saturating_add(self.offset()).min(b'~') as char
+    }
+}
+
+/// a record in the FASTQ format: a strand with a Phred quality score for each nucleotide
+#[derive(Clone)]
+pub struct Fastq<T: Nucleotide> {
+    /// the header up to the first whitespace
+    pub id: String,
+    /// the rest of the header, if any
+    pub description: Option<String>,
+    pub strand: Strand<T>,
+    pub qualities: Vec<u8>,
+}
+
+impl<T: Nucleotide> Fastq<T> {
+    /// creates a new record.
+    /// fails with LengthMismatch if there is not exactly one quality per nucleotide
+    pub fn new(id: String, strand: Strand<T>, qualities: Vec<u8>) -> Result<Self, RosalindError> {
+        if strand.len() != qualities.len() {
+            return Err(RosalindError::LengthMismatch {
+                expected: strand.len(),
+                actual: qualities.len(),
+            });
+        }
+        Ok(Fastq {
+            id,
+            description: None,
+            strand,
+            qualities,
+        })
+    }
+
+    /// parses the structure of the record's identifier
+    pub fn sequence_id(&self) -> SequenceId<'_> {
+        SequenceId::parse(&self.id)
+    }
+
+    /// returns the full text of the header, without the leading `@`
+    pub fn header(&self) -> String {
+        join_header(&self.id, self.description.as_deref())
+    }
+
+    /// Returns the number of nucleotides in the strand, also referred to as its 'length'.
+    pub fn len(&self) -> usize {
+        self.strand.len()
+    }
+
+    /// Returns true if the record's strand contains no nucleotides.
+    pub fn is_empty(&self) -> bool {
+        self.strand.is_empty()
+    }
+
+    /// returns the average quality of the record, or 0.0 if it is empty
+    pub fn mean_quality(&self) -> f64 {
+        if self.qualities.is_empty() {
+            return 0.0;
+        }
+        self.qualities.iter().map(|q| *q as f64).sum::<f64>() / self.qualities.len() as f64
+    }
+
+    /// returns true if at least `percentage` percent (0.0 - 100.0) of the
+    /// nucleotides have a quality of at least `threshold`
+    pub fn passes_filter(&self, threshold: u8, percentage: f64) -> bool {
+        let passing = self.qualities.iter().filter(|q| **q >= threshold).count();
+        passing as f64 * 100.0 >= percentage * self.qualities.len() as f64
+    }
+
+    /// returns a copy of the record with the leading and trailing nucleotides
+    /// whose quality is below `threshold` removed, keeping the soft-mask of the rest
+    pub fn trim(&self, threshold: u8) -> Self {
+        let start = self
+            .qualities
+            .iter()
+            .position(|q| *q >= threshold)
+            .unwrap_or(self.qualities.len());
+        let end = self
+            .qualities
+            .iter()
+            .rposition(|q| *q >= threshold)
+            .map_or(start, |i| i + 1);
+        Fastq {
+            id: self.id.clone(),
+            description: self.description.clone(),
+            strand: self.strand.substrand(start..end),
+            qualities: self.qualities[start..end].to_vec(),
+        }
+    }
+}
+
+impl<T: Nucleotide> From<Fastq<T>> for Fasta<T> {
+    fn from(record: Fastq<T>) -> Self {
+        Fasta {
+            id: record.id,
+            description: record.description,
+            strand: record.strand,
+        }
+    }
+}
+
+/// Lazily reads FASTQ records from a buffered reader. Each record must span
+/// exactly four lines: `@name`, the strand, `+` and the qualities.
+///
+/// The iterator stops after the first error it returns.
+pub struct FastqReader<R: BufRead, T: Nucleotide> {
+    reader: R,
+    encoding: PhredEncoding,
+    line: String,
+    line_number: usize,
+    done: bool,
+    nucleotide: PhantomData<T>,
+}
+
+impl<R: BufRead, T: Nucleotide> FastqReader<R, T> {
+    /// creates a reader for Phred+33 encoded records
+    pub fn new(reader: R) -> Self {
+        Self::with_encoding(reader, PhredEncoding::Phred33)
+    }
+
+    pub fn with_encoding(reader: R, encoding: PhredEncoding) -> Self {
+        FastqReader {
+            reader,
+            encoding,
+            line: String::new(),
+            line_number: 0,
+            done: false,
+            nucleotide: PhantomData,
+        }
+    }
+
+    /// reads the next line into the buffer, returning false at the end of the input
+    fn read_line(&mut self) -> Result<bool, RosalindError> {
+        self.line.clear();
+        let read = self.reader.read_line(&mut self.line)?;
+        if read > 0 {
+            self.line_number += 1;
+        }
+        Ok(read > 0)
+    }
+
+    /// reads the next line, failing if the input ends part way through a record
+    fn read_record_line(&mut self) -> Result<&str, RosalindError> {
+        if !self.read_line()? {
+            return Err(RosalindError::TruncatedRecord {
+                line: self.line_number,
+            });
+        }
+        Ok(self.line.trim_end())
+    }
+
+    fn read_record(&mut self) -> Result<Option<Fastq<T>>, RosalindError> {
+        loop {
+            if !self.read_line()? {
+                return Ok(None);
+            }
+            if !self.line.trim_end().is_empty() {
+                break;
+            }
+        }
+        let (id, description) = split_header(self.line.trim_end().strip_prefix('@').ok_or(
+            RosalindError::MissingHeader {
+                line: self.line_number,
+            },
+        )?);
+
+        let line = self.line_number + 1;
+        let strand: Strand<T> = self
+            .read_record_line()?
+            .parse()
+            .map_err(|e: RosalindError| e.at_line(line))?;
+
+        if !self.read_record_line()?.starts_with('+') {
+            return Err(RosalindError::MissingSeparator {
+                line: self.line_number,
+            });
+        }
+
+        let encoding = self.encoding;
+        let line = self.line_number + 1;
+        let qualities = self
+            .read_record_line()?
+            .chars()
+            .enumerate()
+            .map(|(position, c)| {
+                encoding.decode(c).ok_or(RosalindError::InvalidQuality {
+                    symbol: c,
+                    position,
+                    line,
+                })
+            })
+            .collect::<Result<Vec<u8>, RosalindError>>()?;
+
+        let mut record = Fastq::new(id, strand, qualities)?;
+        record.description = description;
+        Ok(Some(record))
+    }
+}
+
+impl<R: BufRead, T: Nucleotide> Iterator for FastqReader<R, T> {
+    type Item = Result<Fastq<T>, RosalindError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.read_record() {
+            Ok(Some(record)) => Some(Ok(record)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Writes FASTQ records in the four line format.
+pub struct FastqWriter<W: Write> {
+    writer: W,
+    encoding: PhredEncoding,
+}
+
+impl<W: Write> FastqWriter<W> {
+    /// creates a writer for Phred+33 encoded records
+    pub fn new(writer: W) -> Self {
+        Self::with_encoding(writer, PhredEncoding::Phred33)
+    }
+
+    pub fn with_encoding(writer: W, encoding: PhredEncoding) -> Self {
+        FastqWriter { writer, encoding }
+    }
+
+    pub fn write<T: Nucleotide>(&mut self, record: &Fastq<T>) -> Result<(), RosalindError> {
+        let qualities: String = record
+            .qualities
+            .iter()
+            .map(|q| self.encoding.encode(*q))
+            .collect();
+        writeln!(
+            self.writer,
+            "@{}\n{}\n+\n{}",
+            record.header(),
+            record.strand,
+            qualities
+        )?;
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<(), RosalindError> {
+        Ok(self.writer.flush()?)
+    }
+
+    /// flushes and returns the underlying writer
+    pub fn into_inner(mut self) -> Result<W, RosalindError> {
+        self.flush()?;
+        Ok(self.writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nucleotides::dna::DNA;
+
+    const INPUT: &str = "@Rosalind_0049 first read\nGCAGAGACCAGTAGATGTGTTTGCGGACGGTCGGGCTCCATGTGACACAG\n+\nFD@@;C<AI?4BA:=>C<G=:AE=><A??>764A8B797@A:58:527+,\n@Rosalind_0049\nAATCGGCTGCAGGTC\n+\n5?7=1DD?B)*:>C@\n";
+
+    fn read(input: &str) -> Result<Vec<Fastq<DNA>>, RosalindError> {
+        FastqReader::new(input.as_bytes()).collect()
+    }
+
+    #[test]
+    fn test_read_records() -> Result<(), RosalindError> {
+        let records = read(INPUT)?;
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].id, "Rosalind_0049");
+        assert_eq!(records[0].description.as_deref(), Some("first read"));
+        assert_eq!(records[0].len(), 50);
+        assert_eq!(records[0].qualities[0..3], [37, 35, 31]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<(), RosalindError> {
+        let mut writer = FastqWriter::new(vec![]);
+        for record in read(INPUT)? {
+            writer.write(&record)?;
+        }
+        assert_eq!(String::from_utf8(writer.into_inner()?).unwrap(), INPUT);
+        Ok(())
+    }
+
+    #[test]
+    fn test_phred64() {
+        assert_eq!(PhredEncoding::Phred64.decode('h'), Some(40));
+        assert_eq!(PhredEncoding::Phred64.decode('!'), None);
+        assert_eq!(PhredEncoding::Phred64.encode(40), 'h');
+    }
+
+    #[test]
+    fn test_missing_separator() {
+        let result = read("@Rosalind_0049\nGCAG\nFD@@\n");
+        assert!(matches!(
+            result,
+            Err(RosalindError::MissingSeparator { line: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_quality_length_mismatch() {
+        let result = read("@Rosalind_0049\nGCAG\n+\nFD@\n");
+        assert!(matches!(
+            result,
+            Err(RosalindError::LengthMismatch {
+                expected: 4,
+                actual: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn test_truncated_record() {
+        let result = read("@Rosalind_0049\nGCAG\n");
+        assert!(matches!(
+            result,
+            Err(RosalindError::TruncatedRecord { line: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_passes_filter() -> Result<(), RosalindError> {
+        let record: Fastq<DNA> =
+            Fastq::new("r".to_string(), "ACGT".parse()?, vec![20, 30, 10, 25])?;
+        assert!(record.passes_filter(20, 75.0));
+        assert!(!record.passes_filter(20, 80.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim() -> Result<(), RosalindError> {
+        let record: Fastq<DNA> = Fastq::new(
+            "r".to_string(),
+            "ACGTAC".parse()?,
+            vec![5, 30, 10, 25, 19, 2],
+        )?;
+        let trimmed = record.trim(20);
+        assert_eq!(trimmed.strand, "CGT".parse()?);
+        assert_eq!(trimmed.qualities, vec![30, 10, 25]);
+        assert!(record.trim(40).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim_keeps_mask() -> Result<(), RosalindError> {
+        let record: Fastq<DNA> = Fastq::new(
+            "r".to_string(),
+            "acGTac".parse()?,
+            vec![5, 30, 10, 25, 19, 2],
+        )?;
+        assert_eq!(format!("{}", record.trim(20).strand), "cGT");
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_fasta() -> Result<(), RosalindError> {
+        let record: Fastq<DNA> = Fastq::new("r".to_string(), "ACGT".parse()?, vec![5, 5, 5, 5])?;
+        assert!((record.mean_quality() - 5.0).abs() < f64::EPSILON);
+        let fasta = Fasta::from(record);
+        assert_eq!(fasta.id, "r");
+        assert_eq!(fasta.strand, "ACGT".parse()?);
+        Ok(())
+    }
+}
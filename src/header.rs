@@ -0,0 +1,176 @@
+/// Splits the text of a record header (without its leading `>` or `@`) into the
+/// identifier, which runs up to the first whitespace, and the optional description after it.
+pub fn split_header(header: &str) -> (String, Option<String>) {
+    let header = header.trim();
+    match header.split_once(char::is_whitespace) {
+        Some((id, description)) => (id.to_string(), Some(description.trim_start().to_string())),
+        None => (header.to_string(), None),
+    }
+}
+
+/// formats the identifier and description back into the text of a header
+pub fn join_header(id: &str, description: Option<&str>) -> String {
+    match description {
+        Some(description) => format!("{} {}", id, description),
+        None => id.to_string(),
+    }
+}
+
+/// The structure of a record identifier, for the common database formats
+#[derive(Debug, Eq, PartialEq)]
+pub enum SequenceId<'a> {
+    /// UniProtKB identifiers of the form `db|accession|entry_name`, e.g. `sp|P07204|TRBM_HUMAN`
+    UniProt {
+        db: &'a str,
+        accession: &'a str,
+        entry_name: &'a str,
+    },
+    /// NCBI identifiers, either bare versioned accessions like `NC_000913.3` or the
+    /// legacy pipe delimited form, e.g. `gi|49175990|ref|NC_000913.2|`
+    Ncbi {
+        gi: Option<&'a str>,
+        db: Option<&'a str>,
+        accession: &'a str,
+        version: Option<u32>,
+    },
+    /// any other identifier
+    Other(&'a str),
+}
+
+/// database tags used by the legacy NCBI pipe delimited identifiers
+const NCBI_DBS: [&str; 9] = ["gb", "emb", "dbj", "ref", "pir", "prf", "pdb", "tpg", "lcl"];
+
+impl<'a> SequenceId<'a> {
+    /// parses the structure of the identifier. Identifiers that are not recognized are
+    /// returned as Other.
+    pub fn parse(id: &'a str) -> Self {
+        let fields: Vec<&str> = id.split('|').collect();
+        match fields[..] {
+            [db @ ("sp" | "tr"), accession, entry_name] => SequenceId::UniProt {
+                db,
+                accession,
+                entry_name,
+            },
+            ["gi", gi, db, accession, ..] if NCBI_DBS.contains(&db) => {
+                Self::ncbi(Some(gi), Some(db), accession)
+            }
+            [db, accession, ..] if NCBI_DBS.contains(&db) => Self::ncbi(None, Some(db), accession),
+            [accession] if is_versioned_accession(accession) => Self::ncbi(None, None, accession),
+            _ => SequenceId::Other(id),
+        }
+    }
+
+    /// returns the accession of the identifier, without any version.
+    /// identifiers that were not recognized return the whole identifier.
+    pub fn accession(&self) -> &'a str {
+        match self {
+            SequenceId::UniProt { accession, .. } | SequenceId::Ncbi { accession, .. } => accession,
+            SequenceId::Other(id) => id,
+        }
+    }
+
+    fn ncbi(gi: Option<&'a str>, db: Option<&'a str>, accession: &'a str) -> Self {
+        let (accession, version) = match accession.rsplit_once('.') {
+            Some((accession, version)) => match version.parse() {
+                Ok(version) => (accession, Some(version)),
+                Err(_) => (accession, None),
+            },
+            None => (accession, None),
+        };
+        SequenceId::Ncbi {
+            gi,
+            db,
+            accession,
+            version,
+        }
+    }
+}
+
+/// returns true for accessions such as `NC_000913.3` or `AAB12345.1`
+fn is_versioned_accession(id: &str) -> bool {
+    match id.rsplit_once('.') {
+        Some((accession, version)) => {
+            let prefix_len = accession
+                .chars()
+                .take_while(|c| c.is_ascii_uppercase() || *c == '_')
+                .count();
+            prefix_len > 0
+                && prefix_len < accession.len()
+                && accession[prefix_len..].chars().all(|c| c.is_ascii_digit())
+                && !version.is_empty()
+                && version.chars().all(|c| c.is_ascii_digit())
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_header() {
+        assert_eq!(
+            split_header("sp|P07204|TRBM_HUMAN Thrombomodulin OS=Homo sapiens"),
+            (
+                "sp|P07204|TRBM_HUMAN".to_string(),
+                Some("Thrombomodulin OS=Homo sapiens".to_string())
+            )
+        );
+        assert_eq!(
+            split_header("Rosalind_6404"),
+            ("Rosalind_6404".to_string(), None)
+        );
+    }
+
+    macro_rules! parse_test {
+    ($($name:ident: $value:expr,)*) => {
+      $(
+        #[test]
+        fn $name() {
+          let (id, expected) = $value;
+          assert_eq!(SequenceId::parse(id), expected);
+        }
+      )*
+    };
+  }
+
+    parse_test! {
+      parse_uniprot: ("sp|P07204|TRBM_HUMAN", SequenceId::UniProt {
+          db: "sp",
+          accession: "P07204",
+          entry_name: "TRBM_HUMAN",
+      }),
+      parse_ncbi_accession: ("NC_000913.3", SequenceId::Ncbi {
+          gi: None,
+          db: None,
+          accession: "NC_000913",
+          version: Some(3),
+      }),
+      parse_ncbi_gi: ("gi|49175990|ref|NC_000913.2|", SequenceId::Ncbi {
+          gi: Some("49175990"),
+          db: Some("ref"),
+          accession: "NC_000913",
+          version: Some(2),
+      }),
+      parse_ncbi_db: ("gb|AAB12345.1|", SequenceId::Ncbi {
+          gi: None,
+          db: Some("gb"),
+          accession: "AAB12345",
+          version: Some(1),
+      }),
+      parse_other: ("Rosalind_6404", SequenceId::Other("Rosalind_6404")),
+    }
+
+    #[test]
+    fn test_accession() {
+        assert_eq!(
+            SequenceId::parse("sp|P07204|TRBM_HUMAN").accession(),
+            "P07204"
+        );
+        assert_eq!(
+            SequenceId::parse("Rosalind_6404").accession(),
+            "Rosalind_6404"
+        );
+    }
+}
@@ -1,8 +1,18 @@
+pub mod align;
 pub mod args;
+pub mod compression;
+pub mod error;
+pub mod fai;
 pub mod fasta;
+pub mod fastq;
+pub mod header;
+pub mod mass;
 pub mod matrix;
 pub mod motif;
 pub mod nucleotides;
 pub mod phenotype;
 pub mod rabbits;
+pub mod spectrum;
 pub mod strands;
+pub mod subsequence;
+pub mod suffix_array;
@@ -0,0 +1,323 @@
+use crate::error::RosalindError;
+use crate::nucleotides::protein::AminoAcid;
+use crate::strands::Strand;
+
+/// the mass of a proton, added for each charge of an ion
+pub const PROTON_MASS: f64 = 1.007276;
+
+/// Which isotopes masses are calculated for
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum MassType {
+    /// the mass of the most abundant isotope of each element
+    Monoisotopic,
+    /// the mass averaged over the natural abundance of each isotope
+    Average,
+}
+
+impl MassType {
+    pub fn residue_mass(&self, amino_acid: &AminoAcid) -> f64 {
+        match self {
+            MassType::Monoisotopic => AminoAcid::get_monoisotopic_mass(amino_acid),
+            MassType::Average => AminoAcid::get_average_mass(amino_acid),
+        }
+    }
+}
+
+/// A chemical group capping one end of a peptide
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Group {
+    pub name: &'static str,
+    pub monoisotopic: f64,
+    pub average: f64,
+}
+
+impl Group {
+    /// the free amine hydrogen of an unmodified N-terminus
+    pub const HYDROGEN: Group = Group::new("H", 1.007825, 1.00794);
+    /// the free carboxyl hydroxyl of an unmodified C-terminus
+    pub const HYDROXYL: Group = Group::new("OH", 17.00274, 17.00734);
+    /// an acetylated N-terminus
+    pub const ACETYL: Group = Group::new("Acetyl", 43.01839, 43.04462);
+    /// an amidated C-terminus
+    pub const AMIDE: Group = Group::new("NH2", 16.01872, 16.02258);
+
+    pub const fn new(name: &'static str, monoisotopic: f64, average: f64) -> Self {
+        Group {
+            name,
+            monoisotopic,
+            average,
+        }
+    }
+
+    pub fn mass(&self, mass_type: MassType) -> f64 {
+        match mass_type {
+            MassType::Monoisotopic => self.monoisotopic,
+            MassType::Average => self.average,
+        }
+    }
+}
+
+/// A post-translational modification, changing the mass of the residues it applies to
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Modification {
+    pub name: &'static str,
+    /// the change in monoisotopic mass
+    pub monoisotopic: f64,
+    /// the change in average mass
+    pub average: f64,
+    /// the residues that can be modified
+    pub residues: &'static [AminoAcid],
+}
+
+impl Modification {
+    pub const OXIDATION: Modification =
+        Modification::new("Oxidation", 15.994915, 15.9994, &[AminoAcid::M]);
+    pub const PHOSPHORYLATION: Modification = Modification::new(
+        "Phospho",
+        79.966331,
+        79.9799,
+        &[AminoAcid::S, AminoAcid::T, AminoAcid::Y],
+    );
+    pub const CARBAMIDOMETHYL: Modification =
+        Modification::new("Carbamidomethyl", 57.021464, 57.0513, &[AminoAcid::C]);
+    pub const DEAMIDATION: Modification = Modification::new(
+        "Deamidated",
+        0.984016,
+        0.9848,
+        &[AminoAcid::N, AminoAcid::Q],
+    );
+
+    pub const fn new(
+        name: &'static str,
+        monoisotopic: f64,
+        average: f64,
+        residues: &'static [AminoAcid],
+    ) -> Self {
+        Modification {
+            name,
+            monoisotopic,
+            average,
+            residues,
+        }
+    }
+
+    pub fn mass(&self, mass_type: MassType) -> f64 {
+        match mass_type {
+            MassType::Monoisotopic => self.monoisotopic,
+            MassType::Average => self.average,
+        }
+    }
+
+    /// returns the number of residues of the peptide this can modify
+    fn sites(&self, peptide: &Strand<AminoAcid>) -> usize {
+        peptide
+            .iter()
+            .filter(|amino_acid| self.residues.contains(amino_acid))
+            .count()
+    }
+}
+
+/// Calculates the masses of peptides
+#[derive(Clone, Debug)]
+pub struct MassCalculator {
+    mass_type: MassType,
+    n_terminus: Group,
+    c_terminus: Group,
+    /// modifications applied to every residue they can modify
+    fixed: Vec<Modification>,
+    /// modifications that may or may not be applied to each residue
+    variable: Vec<Modification>,
+    /// the most variable modifications applied to a single peptide
+    max_variable: usize,
+}
+
+impl MassCalculator {
+    /// a calculator for unmodified peptides with free termini
+    pub fn new(mass_type: MassType) -> Self {
+        MassCalculator {
+            mass_type,
+            n_terminus: Group::HYDROGEN,
+            c_terminus: Group::HYDROXYL,
+            fixed: vec![],
+            variable: vec![],
+            max_variable: 3,
+        }
+    }
+
+    pub fn monoisotopic() -> Self {
+        Self::new(MassType::Monoisotopic)
+    }
+
+    pub fn average() -> Self {
+        Self::new(MassType::Average)
+    }
+
+    pub fn with_n_terminus(mut self, group: Group) -> Self {
+        self.n_terminus = group;
+        self
+    }
+
+    pub fn with_c_terminus(mut self, group: Group) -> Self {
+        self.c_terminus = group;
+        self
+    }
+
+    pub fn with_fixed(mut self, modification: Modification) -> Self {
+        self.fixed.push(modification);
+        self
+    }
+
+    pub fn with_variable(mut self, modification: Modification) -> Self {
+        self.variable.push(modification);
+        self
+    }
+
+    /// sets the most variable modifications applied to a single peptide
+    pub fn with_max_variable(mut self, max_variable: usize) -> Self {
+        self.max_variable = max_variable;
+        self
+    }
+
+    /// returns the sum of the residue masses with the fixed modifications, without
+    /// the termini. For unmodified peptides this is the mass asked for by PRTM.
+    pub fn residue_mass(&self, peptide: &Strand<AminoAcid>) -> f64 {
+        let residues: f64 = peptide.iter().map(|n| self.mass_type.residue_mass(n)).sum();
+        let modifications: f64 = self
+            .fixed
+            .iter()
+            .map(|m| m.sites(peptide) as f64 * m.mass(self.mass_type))
+            .sum();
+        residues + modifications
+    }
+
+    /// returns the mass of the neutral peptide, with its termini and fixed modifications
+    pub fn mass(&self, peptide: &Strand<AminoAcid>) -> f64 {
+        self.residue_mass(peptide)
+            + self.n_terminus.mass(self.mass_type)
+            + self.c_terminus.mass(self.mass_type)
+    }
+
+    /// returns the mass to charge ratio of the peptide carrying `charge` protons.
+    /// fails with ZeroCharge if `charge` is 0
+    pub fn mz(&self, peptide: &Strand<AminoAcid>, charge: u32) -> Result<f64, RosalindError> {
+        mz(self.mass(peptide), charge)
+    }
+
+    /// returns every distinct mass of the peptide with up to the maximum number of
+    /// variable modifications applied, from lightest to heaviest
+    pub fn variable_masses(&self, peptide: &Strand<AminoAcid>) -> Vec<f64> {
+        let mut masses = vec![(self.mass(peptide), 0)];
+        for modification in &self.variable {
+            let delta = modification.mass(self.mass_type);
+            let sites = modification.sites(peptide);
+            masses = masses
+                .iter()
+                .flat_map(|(mass, applied)| {
+                    (0..=sites.min(self.max_variable - applied))
+                        .map(move |n| (mass + n as f64 * delta, applied + n))
+                })
+                .collect();
+        }
+        let mut masses: Vec<f64> = masses.into_iter().map(|(mass, _)| mass).collect();
+        masses.sort_by(f64::total_cmp);
+        masses.dedup_by(|a, b| (*a - *b).abs() < 1e-9);
+        masses
+    }
+}
+
+/// returns the mass to charge ratio of an ion of the neutral mass carrying `charge` protons.
+/// fails with ZeroCharge if `charge` is 0
+pub fn mz(mass: f64, charge: u32) -> Result<f64, RosalindError> {
+    if charge == 0 {
+        return Err(RosalindError::ZeroCharge);
+    }
+    Ok((mass + charge as f64 * PROTON_MASS) / charge as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-3,
+            "{} is not close to {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_residue_mass_matches_weight() -> Result<(), RosalindError> {
+        let peptide: Strand<AminoAcid> = "SKADYEK".parse()?;
+        assert_eq!(
+            MassCalculator::monoisotopic().residue_mass(&peptide),
+            peptide.weight()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_mass() -> Result<(), RosalindError> {
+        let peptide: Strand<AminoAcid> = "PEPTIDE".parse()?;
+        assert_close(MassCalculator::monoisotopic().mass(&peptide), 799.35996);
+        assert_close(MassCalculator::average().mass(&peptide), 799.83278);
+        assert_close(MassCalculator::monoisotopic().mz(&peptide, 2)?, 400.68726);
+        Ok(())
+    }
+
+    #[test]
+    fn test_mz_zero_charge() -> Result<(), RosalindError> {
+        let peptide: Strand<AminoAcid> = "PEPTIDE".parse()?;
+        assert!(matches!(
+            MassCalculator::monoisotopic().mz(&peptide, 0),
+            Err(RosalindError::ZeroCharge)
+        ));
+        assert!(matches!(mz(799.35996, 0), Err(RosalindError::ZeroCharge)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_termini() -> Result<(), RosalindError> {
+        let peptide: Strand<AminoAcid> = "PEPTIDE".parse()?;
+        let calculator = MassCalculator::monoisotopic()
+            .with_n_terminus(Group::ACETYL)
+            .with_c_terminus(Group::AMIDE);
+        assert_close(calculator.mass(&peptide), 799.35996 + 42.01056 - 0.98402);
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed_modification() -> Result<(), RosalindError> {
+        let peptide: Strand<AminoAcid> = "CCK".parse()?;
+        let unmodified = MassCalculator::monoisotopic().mass(&peptide);
+        let calculator = MassCalculator::monoisotopic().with_fixed(Modification::CARBAMIDOMETHYL);
+        assert_close(calculator.mass(&peptide), unmodified + 2.0 * 57.021464);
+        Ok(())
+    }
+
+    #[test]
+    fn test_variable_modifications() -> Result<(), RosalindError> {
+        let peptide: Strand<AminoAcid> = "MSMK".parse()?;
+        let base = MassCalculator::monoisotopic().mass(&peptide);
+        let calculator = MassCalculator::monoisotopic()
+            .with_variable(Modification::OXIDATION)
+            .with_variable(Modification::PHOSPHORYLATION)
+            .with_max_variable(2);
+        let masses = calculator.variable_masses(&peptide);
+        let oxidation = 15.994915;
+        let phospho = 79.966331;
+        let expected = [
+            base,
+            base + oxidation,
+            base + 2.0 * oxidation,
+            base + phospho,
+            base + oxidation + phospho,
+        ];
+        assert_eq!(masses.len(), expected.len());
+        for (mass, expected) in masses.iter().zip(expected) {
+            assert_close(*mass, expected);
+        }
+        Ok(())
+    }
+}
@@ -1,8 +1,9 @@
-use std::{collections::HashMap, fmt};
+use std::{collections::HashMap, fmt, io::BufRead};
 
 use crate::{
-    fasta::{read_records_from_result_lines, Fasta},
-    nucleotides::Nucleotide,
+    error::RosalindError,
+    fasta::{read_records_from_result_lines, Fasta, FastaReader},
+    nucleotides::{Degenerate, Nucleotide},
     strands::Strand,
 };
 
@@ -14,42 +15,43 @@ pub struct Matrix<T: Nucleotide> {
 
 impl<T: Nucleotide> Matrix<T> {
     /// creates a new Matrix from the provided Fasta records.
-    /// panics if all of the records are not of the same length
-    pub fn new(records: Vec<Fasta<T>>) -> Self {
-        if !records.is_empty() {
-            let strand_len = records[0].len();
-            if records.iter().any(|r| r.len() != strand_len) {
-                panic!("Expected all Fasta records to have the same length")
+    /// fails with RaggedAlignment if all of the records are not of the same length
+    pub fn new(records: Vec<Fasta<T>>) -> Result<Self, RosalindError> {
+        if let Some(first) = records.first() {
+            let strand_len = first.len();
+            if let Some(record) = records.iter().find(|r| r.len() != strand_len) {
+                return Err(RosalindError::RaggedAlignment {
+                    record: record.id.clone(),
+                    expected: strand_len,
+                    actual: record.len(),
+                });
             }
         }
-        Matrix { records }
+        Ok(Matrix { records })
     }
 
     /// reads the lines into a Matrix
-    /// panics if any line is not Ok
-    pub fn new_from_file_lines<TLines, TError>(lines: TLines) -> Self
+    /// fails if any line is not Ok or the records are not of the same length
+    pub fn new_from_file_lines<TLines, TError>(lines: TLines) -> Result<Self, RosalindError>
     where
         TLines: Iterator<Item = Result<String, TError>>,
-        TError: std::fmt::Debug,
-        <T as TryFrom<char>>::Error: std::fmt::Debug,
+        TError: Into<RosalindError>,
     {
-        Self::new(read_records_from_result_lines(lines))
+        Self::new(read_records_from_result_lines(lines)?)
+    }
+
+    /// reads the FASTA records from the reader into a Matrix, holding every record.
+    /// Use Profile.from_reader when only the profile is needed.
+    /// fails if any record cannot be read or the records are not of the same length
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self, RosalindError> {
+        Self::new(FastaReader::new(reader).collect::<Result<Vec<Fasta<T>>, RosalindError>>()?)
     }
 
     /// Constructs the profile of the matrix
     pub fn profile(&self) -> Profile<T> {
         let mut data: HashMap<T, Vec<usize>> = HashMap::new();
         for record in &self.records {
-            for i in 0..record.len() {
-                let nucleotide = record.strand[i];
-                if let Some(arr) = data.get_mut(&nucleotide) {
-                    arr[i] += 1
-                } else {
-                    let mut arr = vec![0; record.len()];
-                    arr[i] = 1;
-                    data.insert(nucleotide, arr);
-                }
-            }
+            count_nucleotides(&mut data, &record.strand);
         }
         Profile::new(data)
     }
@@ -58,6 +60,20 @@ impl<T: Nucleotide> Matrix<T> {
     pub fn consensus(&self) -> Strand<T> {
         self.profile().consensus()
     }
+
+    /// Return the consensus of this matrix using ambiguity codes for ties.
+    /// See Profile.degenerate_consensus.
+    pub fn degenerate_consensus<D: Degenerate<Base = T>>(&self) -> Strand<D> {
+        self.profile().degenerate_consensus()
+    }
+}
+
+/// adds one to the count of each nucleotide of the strand at its position
+fn count_nucleotides<T: Nucleotide>(data: &mut HashMap<T, Vec<usize>>, strand: &Strand<T>) {
+    for (i, nucleotide) in strand.iter().enumerate() {
+        data.entry(*nucleotide)
+            .or_insert_with(|| vec![0; strand.len()])[i] += 1;
+    }
 }
 
 /// The profile matix P is a N x L matrix where
@@ -65,7 +81,8 @@ impl<T: Nucleotide> Matrix<T> {
 /// L = the length of the strands
 /// P(i,j) = the number of times the ith nucleotide appears in the jth position in a strand
 ///
-/// Construct this by calling Matrix.profile()
+/// Construct this by calling Matrix.profile(), or Profile.from_reader to avoid
+/// holding every record in memory
 #[derive(Debug, Eq, PartialEq)]
 pub struct Profile<T: Nucleotide> {
     data: HashMap<T, Vec<usize>>,
@@ -82,6 +99,37 @@ impl<T: Nucleotide> Profile<T> {
         Profile { data, strand_len }
     }
 
+    /// builds the profile one record at a time, so only the counts are kept in memory
+    /// fails if any record cannot be read or the records are not of the same length
+    pub fn from_records<I>(records: I) -> Result<Self, RosalindError>
+    where
+        I: IntoIterator<Item = Result<Fasta<T>, RosalindError>>,
+    {
+        let mut data: HashMap<T, Vec<usize>> = HashMap::new();
+        let mut strand_len = None;
+        for record in records {
+            let record = record?;
+            let expected = *strand_len.get_or_insert(record.len());
+            if record.len() != expected {
+                return Err(RosalindError::RaggedAlignment {
+                    actual: record.len(),
+                    record: record.id,
+                    expected,
+                });
+            }
+            count_nucleotides(&mut data, &record.strand);
+        }
+        Ok(Profile {
+            data,
+            strand_len: strand_len.unwrap_or(0),
+        })
+    }
+
+    /// builds the profile of the FASTA records read from the reader. See Profile.from_records.
+    pub fn from_reader<R: BufRead>(reader: R) -> Result<Self, RosalindError> {
+        Self::from_records(FastaReader::new(reader))
+    }
+
     /// The consensus strand is the strand formed by taking the
     /// most common nucleotide at each position in the profile
     pub fn consensus(&self) -> Strand<T> {
@@ -99,6 +147,25 @@ impl<T: Nucleotide> Profile<T> {
         }
         Strand::new(strand)
     }
+
+    /// The degenerate consensus strand is formed by taking the ambiguity code
+    /// standing for all of the most common nucleotides at each position, so that
+    /// ties are reported rather than broken arbitrarily
+    pub fn degenerate_consensus<D: Degenerate<Base = T>>(&self) -> Strand<D> {
+        let mut strand: Vec<D> = Vec::with_capacity(self.strand_len);
+        for i in 0..self.strand_len {
+            let max = self.data.values().map(|lengths| lengths[i]).max();
+            let mut most_common: Vec<T> = self
+                .data
+                .iter()
+                .filter(|(_, lengths)| Some(lengths[i]) == max)
+                .map(|(nucleotide, _)| *nucleotide)
+                .collect();
+            most_common.sort();
+            strand.push(D::from_bases(&most_common).unwrap());
+        }
+        Strand::new(strand)
+    }
 }
 
 impl<T: Nucleotide> fmt::Display for Profile<T> {
@@ -115,57 +182,122 @@ impl<T: Nucleotide> fmt::Display for Profile<T> {
 
 #[cfg(test)]
 mod tests {
+    use crate::nucleotides::degenerate::DegenerateDNA;
     use crate::nucleotides::dna::DNA;
 
     use super::*;
 
     #[test]
-    fn test_profile() {
+    fn test_profile() -> Result<(), RosalindError> {
         let expected = Profile::new(HashMap::from([
             (DNA::A, vec![5, 1, 0, 0, 5, 5, 0, 0]),
             (DNA::C, vec![0, 0, 1, 4, 2, 0, 6, 1]),
             (DNA::G, vec![1, 1, 6, 3, 0, 1, 0, 0]),
             (DNA::T, vec![1, 5, 0, 0, 0, 1, 1, 6]),
         ]));
-        let actual = Matrix::new(get_test_records()).profile();
+        let actual = Matrix::new(get_test_records())?.profile();
         assert_eq!(actual, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_consensus() -> Result<(), RosalindError> {
+        let expected: Strand<DNA> = "ATGCAACT".parse()?;
+        let actual = Matrix::new(get_test_records())?.consensus();
+        assert_eq!(expected, actual);
+        Ok(())
     }
 
     #[test]
-    fn test_consensus() {
-        let expected: Strand<DNA> = "ATGCAACT".parse().unwrap();
-        let actual = Matrix::new(get_test_records()).consensus();
+    fn test_degenerate_consensus() -> Result<(), RosalindError> {
+        let expected: Strand<DegenerateDNA> = "ATGCAACT".parse()?;
+        let actual = Matrix::new(get_test_records())?.degenerate_consensus();
+        assert_eq!(expected, actual);
+
+        let records = ["ACGT", "GCTT"]
+            .iter()
+            .map(|s| Fasta::from_lines([">Rosalind", *s]))
+            .collect::<Result<Vec<Fasta<DNA>>, RosalindError>>()?;
+        let expected: Strand<DegenerateDNA> = "RCKT".parse()?;
+        let actual = Matrix::new(records)?.degenerate_consensus();
         assert_eq!(expected, actual);
+        Ok(())
+    }
+
+    #[test]
+    fn test_profile_from_reader() -> Result<(), RosalindError> {
+        let input: String = get_test_records()
+            .iter()
+            .map(|record| format!(">{}\n{}\n", record.id, record.strand))
+            .collect();
+        let profile: Profile<DNA> = Profile::from_reader(input.as_bytes())?;
+        assert_eq!(profile, Matrix::new(get_test_records())?.profile());
+
+        let ragged = ">Rosalind_1\nATCCAGCT\n>Rosalind_2\nATG\n";
+        assert!(matches!(
+            Profile::<DNA>::from_reader(ragged.as_bytes()),
+            Err(RosalindError::RaggedAlignment {
+                expected: 8,
+                actual: 3,
+                ..
+            })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_ragged() {
+        let mut records = get_test_records();
+        records.push(Fasta {
+            id: "Rosalind_8".to_string(),
+            description: None,
+            strand: "ATG".parse().unwrap(),
+        });
+        assert!(matches!(
+            Matrix::new(records),
+            Err(RosalindError::RaggedAlignment {
+                expected: 8,
+                actual: 3,
+                ..
+            })
+        ));
     }
 
     fn get_test_records() -> Vec<Fasta<DNA>> {
         vec![
             Fasta {
-                name: "Rosalind_1".to_string(),
+                id: "Rosalind_1".to_string(),
+                description: None,
                 strand: "ATCCAGCT".parse().unwrap(),
             },
             Fasta {
-                name: "Rosalind_2".to_string(),
+                id: "Rosalind_2".to_string(),
+                description: None,
                 strand: "GGGCAACT".parse().unwrap(),
             },
             Fasta {
-                name: "Rosalind_3".to_string(),
+                id: "Rosalind_3".to_string(),
+                description: None,
                 strand: "ATGGATCT".parse().unwrap(),
             },
             Fasta {
-                name: "Rosalind_4".to_string(),
+                id: "Rosalind_4".to_string(),
+                description: None,
                 strand: "AAGCAACC".parse().unwrap(),
             },
             Fasta {
-                name: "Rosalind_5".to_string(),
+                id: "Rosalind_5".to_string(),
+                description: None,
                 strand: "TTGGAACT".parse().unwrap(),
             },
             Fasta {
-                name: "Rosalind_6".to_string(),
+                id: "Rosalind_6".to_string(),
+                description: None,
                 strand: "ATGCCATT".parse().unwrap(),
             },
             Fasta {
-                name: "Rosalind_7".to_string(),
+                id: "Rosalind_7".to_string(),
+                description: None,
                 strand: "ATGGCACT".parse().unwrap(),
             },
         ]
@@ -1,4 +1,3 @@
-use lazy_static::lazy_static;
 use regex::{Error, Regex};
 
 pub type MotifError = Error;
@@ -8,18 +7,58 @@ pub struct Motif {
     re: Regex,
 }
 
+/// returns the residues in a protein that could be the residue in a motif, as the
+/// contents of a regex character class. B, Z and X are ambiguity codes.
+fn possible_residues(residue: char) -> String {
+    match residue {
+        'B' => "BDNX".to_string(),
+        'Z' => "ZEQX".to_string(),
+        'X' => "A-Z".to_string(),
+        'D' | 'N' => format!("{}BX", residue),
+        'E' | 'Q' => format!("{}ZX", residue),
+        c if c.is_ascii_uppercase() => format!("{}X", c),
+        c => c.to_string(),
+    }
+}
+
 impl Motif {
+    /// builds the motif from the Rosalind notation, where `[XY]` matches either X or Y
+    /// and `{X}` matches anything except X. Ambiguity codes in the motif or the protein
+    /// match any residue they could stand for.
     pub fn new(pattern: &str) -> Result<Motif, Error> {
-        lazy_static! {
-            static ref RE: Regex = Regex::new(r"\{(?P<elem>[A-Z])\}").unwrap();
+        let mut re = String::new();
+        let mut in_class = false;
+        let mut in_negation = false;
+        for c in pattern.chars() {
+            match c {
+                '{' => {
+                    in_negation = true;
+                    re.push_str("[^")
+                }
+                '}' => {
+                    in_negation = false;
+                    re.push(']')
+                }
+                c if in_negation => re.push(c),
+                '[' => {
+                    in_class = true;
+                    re.push('[')
+                }
+                ']' => {
+                    in_class = false;
+                    re.push(']')
+                }
+                c if in_class => re.push_str(&possible_residues(c)),
+                c if c.is_ascii_uppercase() => re.push_str(&format!("[{}]", possible_residues(c))),
+                c => re.push(c),
+            }
         }
-        let pattern = RE.replace_all(&pattern, "[^$elem]");
         Ok(Motif {
-            re: Regex::new(&pattern)?,
+            re: Regex::new(&re)?,
         })
     }
 
-    pub fn matches<'a>(&'a self, haystack: &'a str) -> MotifMatches {
+    pub fn matches<'a>(&'a self, haystack: &'a str) -> MotifMatches<'a, 'a> {
         MotifMatches {
             motif: self,
             position: 0,
@@ -60,11 +99,19 @@ mod tests {
     fn test_matches() -> Result<(), Error> {
         let m = Motif::new("N{P}[ST]{P}")?;
         let strand = "MKNKFKTQEELVNHLKTVGFVFANSEIYNGLANAWDYGPLGVLLKNNLKNLWWKEFVTKQKDVVGLDSAIILNPLVWKASGHLDNFSDPLIDCKNCKARYRADKLIESFDENIHIAENSSNEEFAKVLNDYEISCPTCKQFNWTEIRHFNLMFKTYQGVIEDAKNVVYLRPETAQGIFVNFKNVQRSMRLHLPFGIAQIGKSFRNEITPGNFIFRTREFEQMEIEFFLKEESAYDIFDKYLNQIENWLVSACGLSLNNLRKHEHPKEELSHYSKKTIDFEYNFLHGFSELYGIAYRTNYDLSVHMNLSKKDLTYFDEQTKEKYVPHVIEPSVGVERLLYAILTEATFIEKLENDDERILMDLKYDLAPYKIAVMPLVNKLKDKAEEIYGKILDLNISATFDNSGSIGKRYRRQDAIGTIYCLTIDFDSLDDQQDPSFTIRERNSMAQKRIKLSELPLYLNQKAHEDFQRQCQK";
-        let matches: Vec<usize> = m.matches(&strand).collect();
+        let matches: Vec<usize> = m.matches(strand).collect();
         assert_eq!(matches, vec![84, 117, 141, 305, 394]);
         Ok(())
     }
 
+    #[test]
+    fn test_matches_ambiguous_residues() -> Result<(), Error> {
+        let m = Motif::new("N{P}[ST]{P}")?;
+        let matches: Vec<usize> = m.matches("MKBKSAXXTQ").collect();
+        assert_eq!(matches, vec![2, 6]);
+        Ok(())
+    }
+
     mod new_tests {
         use super::*;
 
@@ -85,7 +132,9 @@ mod tests {
 
         new_test! {
           sanity: ("{P}", "[^P]"),
-          no_replacement: ("P", "P"),
+          no_replacement: ("P", "[PX]"),
+          ambiguous_residue: ("B", "[BDNX]"),
+          class: ("[ST]", "[SXTX]"),
           multiple_replacements: ("{P}{A}", "[^P][^A]"),
         }
     }
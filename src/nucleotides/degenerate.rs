@@ -0,0 +1,339 @@
+use super::dna::DNA;
+use super::rna::RNA;
+use super::{Complementable, Degenerate, Nucleotide, Transcribable, Unknown};
+
+/// A DNA nucleotide or one of the IUPAC ambiguity codes standing for a set of them
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DegenerateDNA {
+    A,
+    C,
+    G,
+    T,
+    /// A or G (purine)
+    R,
+    /// C or T (pyrimidine)
+    Y,
+    /// G or C (strong)
+    S,
+    /// A or T (weak)
+    W,
+    /// G or T (keto)
+    K,
+    /// A or C (amino)
+    M,
+    /// not A
+    B,
+    /// not C
+    D,
+    /// not G
+    H,
+    /// not T
+    V,
+    /// any nucleotide
+    N,
+}
+
+/// An RNA nucleotide or one of the IUPAC ambiguity codes standing for a set of them
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DegenerateRNA {
+    A,
+    C,
+    G,
+    U,
+    /// A or G (purine)
+    R,
+    /// C or U (pyrimidine)
+    Y,
+    /// G or C (strong)
+    S,
+    /// A or U (weak)
+    W,
+    /// G or U (keto)
+    K,
+    /// A or C (amino)
+    M,
+    /// not A
+    B,
+    /// not C
+    D,
+    /// not G
+    H,
+    /// not U
+    V,
+    /// any nucleotide
+    N,
+}
+
+// Each code is represented as a 4 bit set of the concrete nucleotides it stands for,
+// with A, C, G and T/U as bits 0 to 3. The complement of a set is then its bit reversal.
+const A: u8 = 0b0001;
+const C: u8 = 0b0010;
+const G: u8 = 0b0100;
+const T: u8 = 0b1000;
+
+/// the codes of each type in the order of MASKS
+const DNA_CODES: [DegenerateDNA; 15] = [
+    DegenerateDNA::A,
+    DegenerateDNA::C,
+    DegenerateDNA::G,
+    DegenerateDNA::T,
+    DegenerateDNA::R,
+    DegenerateDNA::Y,
+    DegenerateDNA::S,
+    DegenerateDNA::W,
+    DegenerateDNA::K,
+    DegenerateDNA::M,
+    DegenerateDNA::B,
+    DegenerateDNA::D,
+    DegenerateDNA::H,
+    DegenerateDNA::V,
+    DegenerateDNA::N,
+];
+const RNA_CODES: [DegenerateRNA; 15] = [
+    DegenerateRNA::A,
+    DegenerateRNA::C,
+    DegenerateRNA::G,
+    DegenerateRNA::U,
+    DegenerateRNA::R,
+    DegenerateRNA::Y,
+    DegenerateRNA::S,
+    DegenerateRNA::W,
+    DegenerateRNA::K,
+    DegenerateRNA::M,
+    DegenerateRNA::B,
+    DegenerateRNA::D,
+    DegenerateRNA::H,
+    DegenerateRNA::V,
+    DegenerateRNA::N,
+];
+const MASKS: [u8; 15] = [
+    A,
+    C,
+    G,
+    T,
+    A | G,
+    C | T,
+    G | C,
+    A | T,
+    G | T,
+    A | C,
+    C | G | T,
+    A | G | T,
+    A | C | T,
+    A | C | G,
+    A | C | G | T,
+];
+
+fn complement_mask(mask: u8) -> u8 {
+    (mask & A) << 3 | (mask & C) << 1 | (mask & G) >> 1 | (mask & T) >> 3
+}
+
+fn index_of_mask(mask: u8) -> Option<usize> {
+    MASKS.iter().position(|m| *m == mask)
+}
+
+impl DegenerateDNA {
+    fn mask(&self) -> u8 {
+        MASKS[*self as usize]
+    }
+
+    fn from_mask(mask: u8) -> Option<Self> {
+        index_of_mask(mask).map(|i| DNA_CODES[i])
+    }
+}
+
+impl DegenerateRNA {
+    fn mask(&self) -> u8 {
+        MASKS[*self as usize]
+    }
+
+    fn from_mask(mask: u8) -> Option<Self> {
+        index_of_mask(mask).map(|i| RNA_CODES[i])
+    }
+}
+
+impl Nucleotide for DegenerateDNA {
+    /// two codes match if there is a nucleotide both could stand for
+    fn matches(&self, other: &Self) -> bool {
+        self.mask() & other.mask() != 0
+    }
+}
+
+impl Nucleotide for DegenerateRNA {
+    /// two codes match if there is a nucleotide both could stand for
+    fn matches(&self, other: &Self) -> bool {
+        self.mask() & other.mask() != 0
+    }
+}
+
+impl Unknown for DegenerateDNA {
+    const UNKNOWN: Self = DegenerateDNA::N;
+}
+
+impl Unknown for DegenerateRNA {
+    const UNKNOWN: Self = DegenerateRNA::N;
+}
+
+impl TryFrom<char> for DegenerateDNA {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'A' => Ok(DegenerateDNA::A),
+            'C' => Ok(DegenerateDNA::C),
+            'G' => Ok(DegenerateDNA::G),
+            'T' => Ok(DegenerateDNA::T),
+            'R' => Ok(DegenerateDNA::R),
+            'Y' => Ok(DegenerateDNA::Y),
+            'S' => Ok(DegenerateDNA::S),
+            'W' => Ok(DegenerateDNA::W),
+            'K' => Ok(DegenerateDNA::K),
+            'M' => Ok(DegenerateDNA::M),
+            'B' => Ok(DegenerateDNA::B),
+            'D' => Ok(DegenerateDNA::D),
+            'H' => Ok(DegenerateDNA::H),
+            'V' => Ok(DegenerateDNA::V),
+            'N' => Ok(DegenerateDNA::N),
+            _ => Err(c),
+        }
+    }
+}
+
+impl TryFrom<char> for DegenerateRNA {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'A' => Ok(DegenerateRNA::A),
+            'C' => Ok(DegenerateRNA::C),
+            'G' => Ok(DegenerateRNA::G),
+            'U' => Ok(DegenerateRNA::U),
+            'R' => Ok(DegenerateRNA::R),
+            'Y' => Ok(DegenerateRNA::Y),
+            'S' => Ok(DegenerateRNA::S),
+            'W' => Ok(DegenerateRNA::W),
+            'K' => Ok(DegenerateRNA::K),
+            'M' => Ok(DegenerateRNA::M),
+            'B' => Ok(DegenerateRNA::B),
+            'D' => Ok(DegenerateRNA::D),
+            'H' => Ok(DegenerateRNA::H),
+            'V' => Ok(DegenerateRNA::V),
+            'N' => Ok(DegenerateRNA::N),
+            _ => Err(c),
+        }
+    }
+}
+
+impl Complementable for DegenerateDNA {
+    fn compliment(&self) -> Self {
+        Self::from_mask(complement_mask(self.mask())).unwrap()
+    }
+}
+
+impl Complementable for DegenerateRNA {
+    fn compliment(&self) -> Self {
+        Self::from_mask(complement_mask(self.mask())).unwrap()
+    }
+}
+
+impl From<DNA> for DegenerateDNA {
+    fn from(dna: DNA) -> Self {
+        DNA_CODES[dna as usize]
+    }
+}
+
+impl From<RNA> for DegenerateRNA {
+    fn from(rna: RNA) -> Self {
+        RNA_CODES[rna as usize]
+    }
+}
+
+impl From<&DegenerateDNA> for DegenerateRNA {
+    fn from(dna: &DegenerateDNA) -> Self {
+        RNA_CODES[*dna as usize]
+    }
+}
+
+impl<'a> Transcribable<'a, DegenerateDNA> for DegenerateRNA {}
+
+impl Degenerate for DegenerateDNA {
+    type Base = DNA;
+
+    fn expand(&self) -> Vec<DNA> {
+        [DNA::A, DNA::C, DNA::G, DNA::T]
+            .into_iter()
+            .filter(|n| self.mask() & MASKS[*n as usize] != 0)
+            .collect()
+    }
+
+    fn from_bases(bases: &[DNA]) -> Option<Self> {
+        Self::from_mask(bases.iter().fold(0, |mask, n| mask | MASKS[*n as usize]))
+    }
+}
+
+impl Degenerate for DegenerateRNA {
+    type Base = RNA;
+
+    fn expand(&self) -> Vec<RNA> {
+        [RNA::A, RNA::C, RNA::G, RNA::U]
+            .into_iter()
+            .filter(|n| self.mask() & MASKS[*n as usize] != 0)
+            .collect()
+    }
+
+    fn from_bases(bases: &[RNA]) -> Option<Self> {
+        Self::from_mask(bases.iter().fold(0, |mask, n| mask | MASKS[*n as usize]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compliment() {
+        assert_eq!(DegenerateDNA::R.compliment(), DegenerateDNA::Y);
+        assert_eq!(DegenerateDNA::K.compliment(), DegenerateDNA::M);
+        assert_eq!(DegenerateDNA::B.compliment(), DegenerateDNA::V);
+        assert_eq!(DegenerateDNA::S.compliment(), DegenerateDNA::S);
+        assert_eq!(DegenerateDNA::N.compliment(), DegenerateDNA::N);
+        assert_eq!(DegenerateRNA::A.compliment(), DegenerateRNA::U);
+        for code in DNA_CODES {
+            assert_eq!(code.compliment().compliment(), code);
+        }
+    }
+
+    #[test]
+    fn test_expand() {
+        assert_eq!(DegenerateDNA::R.expand(), vec![DNA::A, DNA::G]);
+        assert_eq!(DegenerateDNA::T.expand(), vec![DNA::T]);
+        assert_eq!(DegenerateRNA::H.expand(), vec![RNA::A, RNA::C, RNA::U]);
+    }
+
+    #[test]
+    fn test_from_bases() {
+        assert_eq!(
+            DegenerateDNA::from_bases(&[DNA::C, DNA::T]),
+            Some(DegenerateDNA::Y)
+        );
+        assert_eq!(
+            DegenerateDNA::from_bases(&[DNA::A, DNA::C, DNA::G, DNA::T]),
+            Some(DegenerateDNA::N)
+        );
+        assert_eq!(DegenerateDNA::from_bases(&[]), None);
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(DegenerateDNA::R.matches(&DegenerateDNA::A));
+        assert!(DegenerateDNA::N.matches(&DegenerateDNA::T));
+        assert!(!DegenerateDNA::R.matches(&DegenerateDNA::Y));
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(DegenerateDNA::try_from('W'), Ok(DegenerateDNA::W));
+        assert_eq!(DegenerateDNA::try_from('U'), Err('U'));
+        assert_eq!(DegenerateRNA::try_from('U'), Ok(DegenerateRNA::U));
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Complementable, Nucleotide};
+use super::{Complementable, Nucleotide, TwoBit};
 
 #[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum DNA {
@@ -10,6 +10,14 @@ pub enum DNA {
 
 impl Nucleotide for DNA {}
 
+impl TwoBit for DNA {
+    const BASES: &'static [Self; 4] = &[DNA::A, DNA::C, DNA::G, DNA::T];
+
+    fn code(&self) -> u8 {
+        *self as u8
+    }
+}
+
 impl TryFrom<char> for DNA {
     type Error = char;
 
@@ -26,11 +34,11 @@ impl TryFrom<char> for DNA {
 
 impl Complementable for DNA {
     fn compliment(&self) -> Self {
-        match &self {
-            &DNA::A => DNA::T,
-            &DNA::T => DNA::A,
-            &DNA::C => DNA::G,
-            &DNA::G => DNA::C,
+        match self {
+            DNA::A => DNA::T,
+            DNA::T => DNA::A,
+            DNA::C => DNA::G,
+            DNA::G => DNA::C,
         }
     }
 }
@@ -0,0 +1,331 @@
+use super::protein::AminoAcid;
+use super::rna::RNA;
+use lazy_static::lazy_static;
+
+/// A codon of three RNA nucleotides
+pub type Codon = (RNA, RNA, RNA);
+
+/// A table mapping codons to amino acids, with the codons that can start translation
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GeneticCode {
+    id: u8,
+    name: &'static str,
+    /// indexed by codon_index
+    amino_acids: [AminoAcid; 64],
+    starts: [bool; 64],
+}
+
+/// The NCBI translation tables, as (id, name, amino acids, starts). Codons are
+/// ordered with U, C, A, G as the digits of a base 4 number, as in NCBI's gc.prt.
+const TABLES: [(u8, &str, &str, &str); 27] = [
+    (
+        1,
+        "Standard",
+        "FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "---M------**--*----M---------------M----------------------------",
+    ),
+    (
+        2,
+        "Vertebrate Mitochondrial",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSS**VVVVAAAADDEEGGGG",
+        "----------**--------------------MMMM----------**---M------------",
+    ),
+    (
+        3,
+        "Yeast Mitochondrial",
+        "FFLLSSSSYY**CCWWTTTTPPPPHHQQRRRRIIMMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------**----------------------MM---------------M------------",
+    ),
+    (
+        4,
+        "Mold, Protozoan, and Coelenterate Mitochondrial and Mycoplasma/Spiroplasma",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "--MM------**-------M------------MMMM---------------M------------",
+    ),
+    (
+        5,
+        "Invertebrate Mitochondrial",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSSSSVVVVAAAADDEEGGGG",
+        "---M------**--------------------MMMM---------------M------------",
+    ),
+    (
+        6,
+        "Ciliate, Dasycladacean and Hexamita Nuclear",
+        "FFLLSSSSYYQQCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "--------------*--------------------M----------------------------",
+    ),
+    (
+        9,
+        "Echinoderm and Flatworm Mitochondrial",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+        "----------**-----------------------M---------------M------------",
+    ),
+    (
+        10,
+        "Euplotid Nuclear",
+        "FFLLSSSSYY**CCCWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------**-----------------------M----------------------------",
+    ),
+    (
+        11,
+        "Bacterial, Archaeal and Plant Plastid",
+        "FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "---M------**--*----M------------MMMM---------------M------------",
+    ),
+    (
+        12,
+        "Alternative Yeast Nuclear",
+        "FFLLSSSSYY**CC*WLLLSPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------**--*----M---------------M----------------------------",
+    ),
+    (
+        13,
+        "Ascidian Mitochondrial",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSSGGVVVVAAAADDEEGGGG",
+        "---M------**----------------------MM---------------M------------",
+    ),
+    (
+        14,
+        "Alternative Flatworm Mitochondrial",
+        "FFLLSSSSYYY*CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+        "-----------*-----------------------M----------------------------",
+    ),
+    (
+        15,
+        "Blepharisma Macronuclear",
+        "FFLLSSSSYY*QCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------*---*--------------------M----------------------------",
+    ),
+    (
+        16,
+        "Chlorophycean Mitochondrial",
+        "FFLLSSSSYY*LCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------*---*--------------------M----------------------------",
+    ),
+    (
+        21,
+        "Trematode Mitochondrial",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+        "----------**-----------------------M---------------M------------",
+    ),
+    (
+        22,
+        "Scenedesmus obliquus Mitochondrial",
+        "FFLLSS*SYY*LCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "------*---*---*--------------------M----------------------------",
+    ),
+    (
+        23,
+        "Thraustochytrium Mitochondrial",
+        "FF*LSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "--*-------**--*-----------------M--M---------------M------------",
+    ),
+    (
+        24,
+        "Rhabdopleuridae Mitochondrial",
+        "FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSSKVVVVAAAADDEEGGGG",
+        "---M------**-------M---------------M---------------M------------",
+    ),
+    (
+        25,
+        "Candidate Division SR1 and Gracilibacteria",
+        "FFLLSSSSYY**CCGWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "---M------**-----------------------M---------------M------------",
+    ),
+    (
+        26,
+        "Pachysolen tannophilus Nuclear",
+        "FFLLSSSSYY**CC*WLLLAPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------**--*----M---------------M----------------------------",
+    ),
+    (
+        27,
+        "Karyorelict Nuclear",
+        "FFLLSSSSYYQQCCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "--------------*--------------------M----------------------------",
+    ),
+    (
+        28,
+        "Condylostoma Nuclear",
+        "FFLLSSSSYYQQCCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------**--*--------------------M----------------------------",
+    ),
+    (
+        29,
+        "Mesodinium Nuclear",
+        "FFLLSSSSYYYYCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "--------------*--------------------M----------------------------",
+    ),
+    (
+        30,
+        "Peritrich Nuclear",
+        "FFLLSSSSYYEECC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "--------------*--------------------M----------------------------",
+    ),
+    (
+        31,
+        "Blastocrithidia Nuclear",
+        "FFLLSSSSYYEECCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "----------**-----------------------M----------------------------",
+    ),
+    (
+        32,
+        "Balanophoraceae Plastid",
+        "FFLLSSSSYY*WCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        "---M------*---*----M------------MMMM---------------M------------",
+    ),
+    (
+        33,
+        "Cephalodiscidae Mitochondrial",
+        "FFLLSSSSYYY*CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSSKVVVVAAAADDEEGGGG",
+        "---M-------*-------M---------------M---------------M------------",
+    ),
+];
+
+lazy_static! {
+    static ref CODES: Vec<GeneticCode> = TABLES
+        .iter()
+        .map(|(id, name, amino_acids, starts)| GeneticCode::new(*id, name, amino_acids, starts))
+        .collect();
+}
+
+/// the position of a nucleotide in the NCBI codon ordering
+fn base_index(nucleotide: RNA) -> usize {
+    match nucleotide {
+        RNA::U => 0,
+        RNA::C => 1,
+        RNA::A => 2,
+        RNA::G => 3,
+    }
+}
+
+fn codon_index(codon: Codon) -> usize {
+    16 * base_index(codon.0) + 4 * base_index(codon.1) + base_index(codon.2)
+}
+
+/// every codon, ordered by nucleotide
+fn codons() -> impl Iterator<Item = Codon> {
+    const BASES: [RNA; 4] = [RNA::A, RNA::C, RNA::G, RNA::U];
+    BASES.into_iter().flat_map(|first| {
+        BASES
+            .into_iter()
+            .flat_map(move |second| BASES.into_iter().map(move |third| (first, second, third)))
+    })
+}
+
+impl GeneticCode {
+    fn new(id: u8, name: &'static str, amino_acids: &str, starts: &str) -> Self {
+        let mut code = GeneticCode {
+            id,
+            name,
+            amino_acids: [AminoAcid::Stop; 64],
+            starts: [false; 64],
+        };
+        for (i, c) in amino_acids.chars().enumerate() {
+            code.amino_acids[i] = AminoAcid::try_from(c).unwrap_or(AminoAcid::Stop);
+        }
+        for (i, c) in starts.chars().enumerate() {
+            code.starts[i] = c == 'M';
+        }
+        code
+    }
+
+    /// the standard code, NCBI table 1
+    pub fn standard() -> &'static GeneticCode {
+        &CODES[0]
+    }
+
+    /// returns the NCBI translation table with the id, if there is one
+    pub fn from_id(id: u8) -> Option<&'static GeneticCode> {
+        CODES.iter().find(|code| code.id == id)
+    }
+
+    /// returns every NCBI translation table, ordered by id
+    pub fn all() -> &'static [GeneticCode] {
+        &CODES
+    }
+
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn translate(&self, codon: Codon) -> AminoAcid {
+        self.amino_acids[codon_index(codon)]
+    }
+
+    /// returns true if translation can start at the codon. Start codons are
+    /// translated as M when they start a protein.
+    pub fn is_start(&self, codon: Codon) -> bool {
+        self.starts[codon_index(codon)]
+    }
+
+    pub fn start_codons(&self) -> Vec<Codon> {
+        codons().filter(|codon| self.is_start(*codon)).collect()
+    }
+
+    /// returns all codons translated to the amino acid
+    pub fn reverse_translations(&self, amino_acid: &AminoAcid) -> Vec<Codon> {
+        codons()
+            .filter(|codon| self.translate(*codon) == *amino_acid)
+            .collect()
+    }
+}
+
+impl Default for GeneticCode {
+    fn default() -> Self {
+        GeneticCode::standard().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tables() {
+        let ids: Vec<u8> = GeneticCode::all().iter().map(GeneticCode::id).collect();
+        assert_eq!(
+            ids,
+            vec![
+                1, 2, 3, 4, 5, 6, 9, 10, 11, 12, 13, 14, 15, 16, 21, 22, 23, 24, 25, 26, 27, 28,
+                29, 30, 31, 32, 33
+            ]
+        );
+        for (_, _, amino_acids, starts) in TABLES.iter() {
+            assert_eq!(amino_acids.len(), 64);
+            assert_eq!(starts.len(), 64);
+        }
+        assert!(GeneticCode::from_id(7).is_none());
+    }
+
+    #[test]
+    fn test_standard() {
+        let code = GeneticCode::standard();
+        assert_eq!(code.translate((RNA::A, RNA::U, RNA::G)), AminoAcid::M);
+        assert_eq!(code.translate((RNA::U, RNA::G, RNA::A)), AminoAcid::Stop);
+        assert_eq!(code.translate((RNA::G, RNA::C, RNA::U)), AminoAcid::A);
+        assert_eq!(
+            code.start_codons(),
+            vec![
+                (RNA::A, RNA::U, RNA::G),
+                (RNA::C, RNA::U, RNA::G),
+                (RNA::U, RNA::U, RNA::G)
+            ]
+        );
+        assert_eq!(code.reverse_translations(&AminoAcid::Stop).len(), 3);
+    }
+
+    #[test]
+    fn test_vertebrate_mitochondrial() {
+        let code = GeneticCode::from_id(2).unwrap();
+        assert_eq!(code.translate((RNA::U, RNA::G, RNA::A)), AminoAcid::W);
+        assert_eq!(code.translate((RNA::A, RNA::G, RNA::A)), AminoAcid::Stop);
+        assert_eq!(code.translate((RNA::A, RNA::U, RNA::A)), AminoAcid::M);
+        assert!(code.is_start((RNA::A, RNA::U, RNA::U)));
+        assert_eq!(code.reverse_translations(&AminoAcid::Stop).len(), 4);
+    }
+}
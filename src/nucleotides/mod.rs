@@ -2,13 +2,21 @@ use std::convert::{From, TryFrom};
 use std::fmt::Debug;
 use std::hash::Hash;
 
+pub mod degenerate;
 pub mod dna;
+pub mod genetic_code;
 pub mod protein;
 pub mod rna;
 
 /// A base Nucleotide. For convenience, Protein encodings are also considered
 /// a nucleotide.
-pub trait Nucleotide: TryFrom<char> + Sized + Eq + Hash + Clone + Debug + Copy + Ord {}
+pub trait Nucleotide: TryFrom<char> + Sized + Eq + Hash + Clone + Debug + Copy + Ord {
+    /// Returns true if the nucleotides could be the same. Only ambiguity codes can
+    /// match a nucleotide other than themselves.
+    fn matches(&self, other: &Self) -> bool {
+        self == other
+    }
+}
 
 /// Indicates this nucleotide can be transcribed from another
 pub trait Transcribable<'a, T>: Nucleotide + From<&'a T>
@@ -22,3 +30,32 @@ pub trait Complementable: Nucleotide {
     /// Returns the compliment of the current nucleotide
     fn compliment(&self) -> Self;
 }
+
+/// Nucleotides implementing this trait are IUPAC ambiguity codes, each standing
+/// for a set of concrete nucleotides.
+pub trait Degenerate: Nucleotide + From<Self::Base> {
+    type Base: Nucleotide;
+
+    /// Returns the concrete nucleotides this code stands for
+    fn expand(&self) -> Vec<Self::Base>;
+
+    /// Returns the code standing for exactly the given nucleotides, or None if there are none
+    fn from_bases(bases: &[Self::Base]) -> Option<Self>;
+}
+
+/// Nucleotides implementing this trait have a symbol for an unknown nucleotide,
+/// such as N for DNA
+pub trait Unknown: Nucleotide {
+    const UNKNOWN: Self;
+}
+
+/// Nucleotides implementing this trait can be stored in 2 bits. The codes are
+/// ordered A, C, G, T/U, so the compliment of a code is its bitwise not and
+/// the codes of C and G are those whose two bits differ.
+pub trait TwoBit: Nucleotide + 'static {
+    /// the nucleotides, indexed by their codes
+    const BASES: &'static [Self; 4];
+
+    /// Returns the 2 bit code of the nucleotide
+    fn code(&self) -> u8;
+}
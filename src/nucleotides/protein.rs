@@ -1,7 +1,9 @@
-use super::Nucleotide;
+use super::genetic_code::GeneticCode;
+use super::{Nucleotide, Unknown};
 use crate::nucleotides::rna::RNA;
+use std::fmt;
 
-#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Copy, Clone, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub enum AminoAcid {
     A,
     C,
@@ -23,10 +25,29 @@ pub enum AminoAcid {
     V,
     W,
     Y,
+    /// selenocysteine
+    U,
+    /// pyrrolysine
+    O,
+    /// D or N
+    B,
+    /// E or Q
+    Z,
+    /// any amino acid
+    X,
     Stop,
 }
 
-impl Nucleotide for AminoAcid {}
+impl Nucleotide for AminoAcid {
+    /// B, Z and X match any amino acid they could stand for
+    fn matches(&self, other: &Self) -> bool {
+        self == other || self.could_be(other) || other.could_be(self)
+    }
+}
+
+impl Unknown for AminoAcid {
+    const UNKNOWN: Self = AminoAcid::X;
+}
 
 impl TryFrom<char> for AminoAcid {
     type Error = char;
@@ -53,12 +74,72 @@ impl TryFrom<char> for AminoAcid {
             'V' => Ok(AminoAcid::V),
             'W' => Ok(AminoAcid::W),
             'Y' => Ok(AminoAcid::Y),
+            'U' => Ok(AminoAcid::U),
+            'O' => Ok(AminoAcid::O),
+            'B' => Ok(AminoAcid::B),
+            'Z' => Ok(AminoAcid::Z),
+            'X' => Ok(AminoAcid::X),
+            '*' => Ok(AminoAcid::Stop),
             _ => Err(c),
         }
     }
 }
 
+/// Amino acids are written as their one letter codes, with `*` for Stop
+impl fmt::Debug for AminoAcid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            AminoAcid::Stop => '*',
+            // every other variant is named after its code
+            _ => AMINO_ACID_CODES[*self as usize],
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+/// the one letter codes, in the order of the variants
+const AMINO_ACID_CODES: [char; 25] = [
+    'A', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'K', 'L', 'M', 'N', 'P', 'Q', 'R', 'S', 'T', 'V', 'W',
+    'Y', 'U', 'O', 'B', 'Z', 'X',
+];
+
 impl AminoAcid {
+    /// the 20 amino acids of the standard genetic code
+    pub const STANDARD: [AminoAcid; 20] = [
+        AminoAcid::A,
+        AminoAcid::C,
+        AminoAcid::D,
+        AminoAcid::E,
+        AminoAcid::F,
+        AminoAcid::G,
+        AminoAcid::H,
+        AminoAcid::I,
+        AminoAcid::K,
+        AminoAcid::L,
+        AminoAcid::M,
+        AminoAcid::N,
+        AminoAcid::P,
+        AminoAcid::Q,
+        AminoAcid::R,
+        AminoAcid::S,
+        AminoAcid::T,
+        AminoAcid::V,
+        AminoAcid::W,
+        AminoAcid::Y,
+    ];
+
+    /// returns true if this is an ambiguity code that could stand for the other amino acid
+    fn could_be(&self, other: &AminoAcid) -> bool {
+        match self {
+            AminoAcid::B => matches!(other, AminoAcid::D | AminoAcid::N),
+            AminoAcid::Z => matches!(other, AminoAcid::E | AminoAcid::Q),
+            AminoAcid::X => *other != AminoAcid::Stop,
+            _ => false,
+        }
+    }
+
+    /// returns the monoisotopic mass of the residue. B and Z are given the mean of
+    /// the masses they could stand for, and X the mass of an averagine residue.
     pub fn get_monoisotopic_mass(amino_acid: &AminoAcid) -> f64 {
         match amino_acid {
             AminoAcid::A => 71.03711,
@@ -81,45 +162,80 @@ impl AminoAcid {
             AminoAcid::V => 99.06841,
             AminoAcid::W => 186.07931,
             AminoAcid::Y => 163.06333,
+            AminoAcid::U => 150.95364,
+            AminoAcid::O => 237.14773,
+            AminoAcid::B => 114.53494,
+            AminoAcid::Z => 128.55059,
+            AminoAcid::X => 111.05431,
+            AminoAcid::Stop => 0.0,
+        }
+    }
+
+    /// returns the average mass of the residue, for the natural abundance of each
+    /// isotope. B, Z and X are treated as in get_monoisotopic_mass.
+    pub fn get_average_mass(amino_acid: &AminoAcid) -> f64 {
+        match amino_acid {
+            AminoAcid::A => 71.0788,
+            AminoAcid::C => 103.1388,
+            AminoAcid::D => 115.0886,
+            AminoAcid::E => 129.1155,
+            AminoAcid::F => 147.1766,
+            AminoAcid::G => 57.0519,
+            AminoAcid::H => 137.1411,
+            AminoAcid::I => 113.1594,
+            AminoAcid::K => 128.1741,
+            AminoAcid::L => 113.1594,
+            AminoAcid::M => 131.1926,
+            AminoAcid::N => 114.1038,
+            AminoAcid::P => 97.1167,
+            AminoAcid::Q => 128.1307,
+            AminoAcid::R => 156.1875,
+            AminoAcid::S => 87.0782,
+            AminoAcid::T => 101.1051,
+            AminoAcid::V => 99.1326,
+            AminoAcid::W => 186.2132,
+            AminoAcid::Y => 163.1760,
+            AminoAcid::U => 150.0379,
+            AminoAcid::O => 237.2982,
+            AminoAcid::B => 114.5962,
+            AminoAcid::Z => 128.6231,
+            AminoAcid::X => 111.1254,
             AminoAcid::Stop => 0.0,
         }
     }
 }
 
+/// translates the codon with the standard genetic code
 impl From<(RNA, RNA, RNA)> for AminoAcid {
     fn from(codon: (RNA, RNA, RNA)) -> Self {
-        match codon {
-            (RNA::G, RNA::C, _) => AminoAcid::A,
-            (RNA::U, RNA::G, RNA::U) | (RNA::U, RNA::G, RNA::C) => AminoAcid::C,
-            (RNA::G, RNA::A, RNA::U) | (RNA::G, RNA::A, RNA::C) => AminoAcid::D,
-            (RNA::G, RNA::A, RNA::A) | (RNA::G, RNA::A, RNA::G) => AminoAcid::E,
-            (RNA::U, RNA::U, RNA::U) | (RNA::U, RNA::U, RNA::C) => AminoAcid::F,
-            (RNA::G, RNA::G, _) => AminoAcid::G,
-            (RNA::C, RNA::A, RNA::U) | (RNA::C, RNA::A, RNA::C) => AminoAcid::H,
-            (RNA::A, RNA::U, RNA::U) | (RNA::A, RNA::U, RNA::C) | (RNA::A, RNA::U, RNA::A) => {
-                AminoAcid::I
-            }
-            (RNA::A, RNA::A, RNA::A) | (RNA::A, RNA::A, RNA::G) => AminoAcid::K,
-            (RNA::U, RNA::U, RNA::A) | (RNA::U, RNA::U, RNA::G) | (RNA::C, RNA::U, _) => {
-                AminoAcid::L
-            }
-            (RNA::A, RNA::U, RNA::G) => AminoAcid::M,
-            (RNA::A, RNA::A, RNA::U) | (RNA::A, RNA::A, RNA::C) => AminoAcid::N,
-            (RNA::C, RNA::C, _) => AminoAcid::P,
-            (RNA::C, RNA::A, RNA::A) | (RNA::C, RNA::A, RNA::G) => AminoAcid::Q,
-            (RNA::C, RNA::G, _) | (RNA::A, RNA::G, RNA::A) | (RNA::A, RNA::G, RNA::G) => {
-                AminoAcid::R
-            }
-            (RNA::U, RNA::C, _) | (RNA::A, RNA::G, RNA::U) | (RNA::A, RNA::G, RNA::C) => {
-                AminoAcid::S
-            }
-            (RNA::A, RNA::C, _) => AminoAcid::T,
-            (RNA::G, RNA::U, _) => AminoAcid::V,
-            (RNA::U, RNA::G, RNA::G) => AminoAcid::W,
-            (RNA::U, RNA::A, RNA::U) | (RNA::U, RNA::A, RNA::C) => AminoAcid::Y,
-            (RNA::U, RNA::A, RNA::A) | (RNA::U, RNA::A, RNA::G) | (RNA::U, RNA::G, RNA::A) => {
-                AminoAcid::Stop
-            }
-        }
+        GeneticCode::standard().translate(codon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(AminoAcid::try_from('*'), Ok(AminoAcid::Stop));
+        assert_eq!(AminoAcid::try_from('U'), Ok(AminoAcid::U));
+        assert_eq!(AminoAcid::try_from('J'), Err('J'));
+    }
+
+    #[test]
+    fn test_debug() {
+        assert_eq!(format!("{:?}", AminoAcid::Stop), "*");
+        assert_eq!(format!("{:?}", AminoAcid::O), "O");
+        assert_eq!(format!("{:?}", AminoAcid::Y), "Y");
+    }
+
+    #[test]
+    fn test_matches() {
+        assert!(AminoAcid::B.matches(&AminoAcid::N));
+        assert!(AminoAcid::Q.matches(&AminoAcid::Z));
+        assert!(AminoAcid::X.matches(&AminoAcid::W));
+        assert!(!AminoAcid::B.matches(&AminoAcid::E));
+        assert!(!AminoAcid::X.matches(&AminoAcid::Stop));
     }
 }
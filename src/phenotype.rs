@@ -12,7 +12,7 @@ pub struct PhenotypeCombinator {
 
 impl PhenotypeCombinator {
     pub fn new(p1: Phenotype, p2: Phenotype) -> Self {
-        PhenotypeCombinator { p1: p1, p2: p2 }
+        PhenotypeCombinator { p1, p2 }
     }
 
     /// returns the probability (0.0 - 1.0) that the resulting combination would
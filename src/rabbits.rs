@@ -19,16 +19,16 @@ impl Rabbits {
         let mut rabbits = Rabbits {
             offspring_per_step: offspring.unwrap_or(1),
             cohorts: vec![],
-            max_age: max_age,
+            max_age,
         };
         rabbits.add_cohort(1);
         rabbits
     }
 
-    fn add_cohort(&mut self, population: u128) -> () {
+    fn add_cohort(&mut self, population: u128) {
         self.cohorts.push(RabbitCohort {
             age: 1,
-            population: population,
+            population,
             max_age: self.max_age,
         });
     }
@@ -54,7 +54,7 @@ impl Iterator for Rabbits {
         let total_pop = self.get_total_population();
         let adult_population_this_step = self.get_adult_population();
         self.cohorts.iter_mut().for_each(|c| c.age += 1);
-        if self.max_age != None {
+        if self.max_age.is_some() {
             self.cohorts.retain(|c| !c.is_dead());
         }
         if adult_population_this_step > 0 {
@@ -81,10 +81,7 @@ struct RabbitCohort {
 
 impl RabbitCohort {
     fn is_dead(&self) -> bool {
-        match self.max_age {
-            Some(max) if self.age > max => true,
-            _ => false,
-        }
+        matches!(self.max_age, Some(max) if self.age > max)
     }
 }
 
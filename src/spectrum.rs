@@ -0,0 +1,208 @@
+use crate::error::RosalindError;
+use crate::nucleotides::protein::AminoAcid;
+use crate::strands::Strand;
+
+/// the tolerance used when comparing masses given to 5 decimal places
+pub const DEFAULT_TOLERANCE: f64 = 0.001;
+
+/// returns the standard amino acid whose monoisotopic mass is closest to the mass,
+/// if it is within the tolerance
+pub fn amino_acid_for_mass(mass: f64, tolerance: f64) -> Option<AminoAcid> {
+    AminoAcid::STANDARD
+        .iter()
+        .map(|aa| (*aa, (AminoAcid::get_monoisotopic_mass(aa) - mass).abs()))
+        .filter(|(_, error)| *error <= tolerance)
+        .min_by(|(_, e1), (_, e2)| e1.total_cmp(e2))
+        .map(|(aa, _)| aa)
+}
+
+/// infers the protein from its prefix spectrum, the masses of each of its prefixes
+/// in increasing order. Fails with UnmatchedMass if the difference between
+/// consecutive masses is not the mass of an amino acid.
+pub fn protein_from_prefix_spectrum(
+    spectrum: &[f64],
+    tolerance: f64,
+) -> Result<Strand<AminoAcid>, RosalindError> {
+    spectrum
+        .windows(2)
+        .map(|pair| {
+            let mass = pair[1] - pair[0];
+            amino_acid_for_mass(mass, tolerance).ok_or(RosalindError::UnmatchedMass { mass })
+        })
+        .collect::<Result<Vec<AminoAcid>, RosalindError>>()
+        .map(Strand::new)
+}
+
+/// reconstructs a peptide from the masses of its b and y ions: the prefix and the
+/// matching suffix for every cut of the peptide, including the two empty cuts.
+/// returns None if no chain of ions differs by amino acid masses throughout.
+pub fn protein_from_ions(ions: &[f64], tolerance: f64) -> Option<Strand<AminoAcid>> {
+    if ions.len() < 2 {
+        return None;
+    }
+    let length = (ions.len() - 2) / 2;
+    let mut ions = ions.to_vec();
+    ions.sort_by(f64::total_cmp);
+    let mut protein = Vec::with_capacity(length);
+    (0..ions.len())
+        .any(|start| extend_chain(&ions, start, length, tolerance, &mut protein))
+        .then(|| Strand::new(protein))
+}
+
+/// extends the protein with ions after `from` until it has `length` amino acids,
+/// backtracking when there is no next ion
+fn extend_chain(
+    ions: &[f64],
+    from: usize,
+    length: usize,
+    tolerance: f64,
+    protein: &mut Vec<AminoAcid>,
+) -> bool {
+    if protein.len() == length {
+        return true;
+    }
+    for next in from + 1..ions.len() {
+        if let Some(aa) = amino_acid_for_mass(ions[next] - ions[from], tolerance) {
+            protein.push(aa);
+            if extend_chain(ions, next, length, tolerance, protein) {
+                return true;
+            }
+            protein.pop();
+        }
+    }
+    false
+}
+
+/// returns the masses of every non-empty proper prefix and suffix of the protein,
+/// the b and y ions of its fragments
+pub fn complete_spectrum(protein: &Strand<AminoAcid>) -> Vec<f64> {
+    let masses: Vec<f64> = protein
+        .iter()
+        .map(AminoAcid::get_monoisotopic_mass)
+        .collect();
+    let total: f64 = masses.iter().sum();
+    let mut spectrum = Vec::with_capacity(2 * masses.len());
+    let mut prefix = 0.0;
+    for mass in &masses[..masses.len().saturating_sub(1)] {
+        prefix += mass;
+        spectrum.push(prefix);
+        spectrum.push(total - prefix);
+    }
+    spectrum
+}
+
+/// returns the shift with the greatest multiplicity in the Minkowski difference
+/// `spectrum_1 ⊖ spectrum_2`, as (multiplicity, shift). Differences within the
+/// tolerance of each other are counted as the same shift.
+pub fn max_convolution(spectrum_1: &[f64], spectrum_2: &[f64], tolerance: f64) -> (usize, f64) {
+    let mut differences: Vec<f64> = spectrum_1
+        .iter()
+        .flat_map(|s1| spectrum_2.iter().map(move |s2| s1 - s2))
+        .collect();
+    differences.sort_by(f64::total_cmp);
+
+    let mut best = (0, 0.0);
+    let mut start = 0;
+    for end in 0..differences.len() {
+        while differences[end] - differences[start] > tolerance {
+            start += 1;
+        }
+        if end - start + 1 > best.0 {
+            best = (end - start + 1, differences[start]);
+        }
+    }
+    best
+}
+
+/// returns the candidate whose complete spectrum shares the most masses with the
+/// spectrum, after shifting by the best convolution shift, with that multiplicity.
+/// ties are won by the earliest candidate.
+pub fn match_spectrum<'a>(
+    spectrum: &[f64],
+    candidates: &'a [Strand<AminoAcid>],
+    tolerance: f64,
+) -> Option<(usize, &'a Strand<AminoAcid>)> {
+    candidates
+        .iter()
+        .map(|candidate| {
+            let (multiplicity, _) =
+                max_convolution(spectrum, &complete_spectrum(candidate), tolerance);
+            (multiplicity, candidate)
+        })
+        .rev()
+        .max_by_key(|(multiplicity, _)| *multiplicity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_protein_from_prefix_spectrum() -> Result<(), RosalindError> {
+        let spectrum = [3524.8542, 3710.9335, 3841.974, 3970.0326, 4057.0646];
+        let protein = protein_from_prefix_spectrum(&spectrum, DEFAULT_TOLERANCE)?;
+        assert_eq!(protein.to_string(), "WMQS");
+        Ok(())
+    }
+
+    #[test]
+    fn test_protein_from_prefix_spectrum_unmatched() {
+        assert!(matches!(
+            protein_from_prefix_spectrum(&[100.0, 110.0], DEFAULT_TOLERANCE),
+            Err(RosalindError::UnmatchedMass { .. })
+        ));
+    }
+
+    #[test]
+    fn test_protein_from_ions() {
+        let ions = [
+            610.391039105,
+            738.485999105,
+            766.492149105,
+            863.544909105,
+            867.528589105,
+            992.587499105,
+            995.623549105,
+            1120.6824591,
+            1124.6661391,
+            1221.7188991,
+            1249.7250491,
+            1377.8200091,
+        ];
+        let protein = protein_from_ions(&ions, DEFAULT_TOLERANCE).unwrap();
+        assert_eq!(protein.len(), 5);
+        // I and L have the same mass, as do the reversed b and y chains
+        assert!(["KEKEP", "PEKEK"].contains(&protein.to_string().as_str()));
+    }
+
+    #[test]
+    fn test_max_convolution() {
+        let spectrum_1 = [
+            186.07931, 287.12699, 548.20532, 580.18077, 681.22845, 706.27446, 782.27613, 968.35544,
+            968.35544,
+        ];
+        let spectrum_2 = [
+            101.04768, 158.06914, 202.09536, 318.09979, 419.14747, 463.17369,
+        ];
+        let (multiplicity, shift) = max_convolution(&spectrum_1, &spectrum_2, DEFAULT_TOLERANCE);
+        assert_eq!(multiplicity, 3);
+        assert!((shift - 85.03163).abs() < DEFAULT_TOLERANCE);
+    }
+
+    #[test]
+    fn test_match_spectrum() -> Result<(), RosalindError> {
+        let candidates: Vec<Strand<AminoAcid>> = ["GSDMQS", "VWICN", "IASWMQS", "PVSMGAD"]
+            .iter()
+            .map(|s| s.parse())
+            .collect::<Result<_, _>>()?;
+        let spectrum = [
+            445.17838, 115.02694, 186.07931, 314.13789, 317.1198, 215.09061,
+        ];
+        let (multiplicity, protein) =
+            match_spectrum(&spectrum, &candidates, DEFAULT_TOLERANCE).unwrap();
+        assert_eq!(multiplicity, 3);
+        // IASWMQS and PVSMGAD also share 3 masses, but GSDMQS comes first
+        assert_eq!(protein.to_string(), "GSDMQS");
+        Ok(())
+    }
+}
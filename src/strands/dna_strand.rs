@@ -1,27 +1,34 @@
 use crate::nucleotides::dna::DNA;
-use crate::strands::Strand;
+use crate::strands::{Strand, StrandSlice};
 
 impl Strand<DNA> {
+    pub fn get_gc_content(&self) -> f32 {
+        self.as_slice().get_gc_content()
+    }
+}
+
+impl StrandSlice<'_, DNA> {
     pub fn get_gc_content(&self) -> f32 {
         let gc_count = self
-            .nucleotides
             .iter()
             .filter(|n| **n == DNA::G || **n == DNA::C)
             .count();
-        gc_count as f32 / self.nucleotides.len() as f32
+        gc_count as f32 / self.len() as f32
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::RosalindError;
 
     #[test]
-    fn test_gc_content() -> Result<(), char> {
+    fn test_gc_content() -> Result<(), RosalindError> {
         let dna_string: Strand<DNA> =
       "CCACCCTCGTGGTATGGCTAGGCATTCAGGAACCGGAGAACGCTTCAGACCAGCCCGGACTGGGAACCTGCGGGCAGTAGGTGGAAT"
         .parse()?;
-        assert_eq!(dna_string.get_gc_content() * 100.0, 60.919540);
+        assert_eq!(dna_string.get_gc_content() * 100.0, 60.91954);
+        assert_eq!(dna_string.slice(0..4).get_gc_content(), 0.75);
         Ok(())
     }
 }
@@ -0,0 +1,386 @@
+use super::{Strand, StrandSlice};
+use crate::nucleotides::Nucleotide;
+use crate::suffix_array::{alphabet, suffix_array, symbol};
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// the symbol of the sentinel ending the text, smaller than every nucleotide
+const SENTINEL: u8 = 0;
+/// the rows of the BWT between each stored count of every symbol before them
+const OCCURRENCE_RATE: usize = 64;
+/// the positions of the strand between each stored suffix array entry
+const SUFFIX_RATE: usize = 32;
+
+/// An FM-index of a strand: its Burrows-Wheeler transform, with enough of its suffix
+/// array and symbol counts to find patterns in time depending on the length of the
+/// pattern rather than the strand. Build it once and query it with as many patterns
+/// as needed.
+///
+/// Nucleotides are compared exactly, so ambiguity codes only match themselves.
+pub struct StrandIndex<T: Nucleotide> {
+    /// the distinct nucleotides of the strand, in order. Nucleotide `alphabet[c]` is
+    /// symbol `c + 1` of the BWT.
+    alphabet: Vec<T>,
+    /// the symbol before each suffix of the strand and sentinel, in suffix array order
+    bwt: Vec<u8>,
+    /// the number of symbols smaller than each symbol
+    smaller: Vec<usize>,
+    /// the number of each symbol in the BWT before every OCCURRENCE_RATE rows
+    occurrences: Vec<usize>,
+    /// the suffix array entries that are multiples of SUFFIX_RATE, by row
+    sampled: HashMap<usize, usize>,
+}
+
+impl<T: Nucleotide> StrandIndex<T> {
+    /// builds the index in O(n) time
+    pub fn new<'a>(strand: impl Into<StrandSlice<'a, T>>) -> Self
+    where
+        T: 'a,
+    {
+        let strand = strand.into();
+        let alphabet = alphabet(strand.iter());
+        let width = alphabet.len() + 1;
+        assert!(width <= u8::MAX as usize, "too many distinct nucleotides");
+        let text: Vec<usize> = strand.iter().map(|n| symbol(&alphabet, n)).collect();
+        // the suffix of the sentinel alone comes first
+        let suffixes: Vec<usize> = std::iter::once(text.len())
+            .chain(suffix_array(&text, alphabet.len()))
+            .collect();
+
+        let bwt: Vec<u8> = suffixes
+            .iter()
+            .map(|suffix| match suffix {
+                0 => SENTINEL,
+                _ => text[suffix - 1] as u8 + 1,
+            })
+            .collect();
+        let mut counts = vec![0; width];
+        let mut occurrences = Vec::with_capacity((bwt.len() / OCCURRENCE_RATE + 1) * width);
+        for (row, symbol) in bwt.iter().enumerate() {
+            if row % OCCURRENCE_RATE == 0 {
+                occurrences.extend_from_slice(&counts);
+            }
+            counts[*symbol as usize] += 1;
+        }
+        // ranks are also taken at the end of the BWT, which needs its own checkpoint
+        // when the BWT fills its last block
+        if bwt.len().is_multiple_of(OCCURRENCE_RATE) {
+            occurrences.extend_from_slice(&counts);
+        }
+        let mut smaller = vec![0; width];
+        for symbol in 1..width {
+            smaller[symbol] = smaller[symbol - 1] + counts[symbol - 1];
+        }
+        let sampled = suffixes
+            .iter()
+            .enumerate()
+            .filter(|(_, suffix)| *suffix % SUFFIX_RATE == 0)
+            .map(|(row, suffix)| (row, *suffix))
+            .collect();
+        StrandIndex {
+            alphabet,
+            bwt,
+            smaller,
+            occurrences,
+            sampled,
+        }
+    }
+
+    /// returns the length of the indexed strand
+    pub fn len(&self) -> usize {
+        self.bwt.len() - 1
+    }
+
+    /// returns true if the indexed strand is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// returns the Burrows-Wheeler transform of the strand, the last column of its sorted
+    /// rotations once a sentinel is appended, with None for the sentinel
+    pub fn bwt(&self) -> Vec<Option<T>> {
+        self.bwt
+            .iter()
+            .map(|symbol| self.nucleotide(*symbol))
+            .collect()
+    }
+
+    /// rebuilds the indexed strand from its BWT
+    pub fn to_strand(&self) -> Strand<T> {
+        inverse_bwt(&self.bwt()).expect("the BWT has a single sentinel")
+    }
+
+    fn nucleotide(&self, symbol: u8) -> Option<T> {
+        match symbol {
+            SENTINEL => None,
+            _ => Some(self.alphabet[symbol as usize - 1]),
+        }
+    }
+
+    /// returns the number of times the symbol occurs in the BWT before the row
+    fn rank(&self, symbol: u8, row: usize) -> usize {
+        let checkpoint = row / OCCURRENCE_RATE;
+        let stored = self.occurrences[checkpoint * (self.alphabet.len() + 1) + symbol as usize];
+        stored
+            + self.bwt[checkpoint * OCCURRENCE_RATE..row]
+                .iter()
+                .filter(|s| **s == symbol)
+                .count()
+    }
+
+    /// returns the rows of the suffixes starting with the symbol followed by a suffix
+    /// in the rows. This is the LF mapping applied to a range.
+    fn extend(&self, symbol: u8, rows: &Range<usize>) -> Range<usize> {
+        let start = self.smaller[symbol as usize];
+        start + self.rank(symbol, rows.start)..start + self.rank(symbol, rows.end)
+    }
+
+    /// returns the rows of the suffixes starting with the pattern, matching it from its
+    /// last nucleotide to its first in O(m) time
+    fn backward_search(&self, pattern: StrandSlice<'_, T>) -> Range<usize> {
+        let mut rows = 0..self.bwt.len();
+        for nucleotide in pattern.iter().rev() {
+            let Ok(index) = self.alphabet.binary_search(nucleotide) else {
+                return 0..0;
+            };
+            rows = self.extend(index as u8 + 1, &rows);
+            if rows.is_empty() {
+                break;
+            }
+        }
+        rows
+    }
+
+    /// returns the position in the strand of the suffix in the row, stepping back
+    /// through the strand to the nearest sampled position
+    fn position(&self, mut row: usize) -> usize {
+        let mut steps = 0;
+        loop {
+            if let Some(suffix) = self.sampled.get(&row) {
+                return suffix + steps;
+            }
+            let symbol = self.bwt[row];
+            row = self.smaller[symbol as usize] + self.rank(symbol, row);
+            steps += 1;
+        }
+    }
+
+    /// returns the number of times the pattern occurs in the strand
+    pub fn count<'b>(&self, pattern: impl Into<StrandSlice<'b, T>>) -> usize
+    where
+        T: 'b,
+    {
+        self.backward_search(pattern.into()).len()
+    }
+
+    /// returns every position the pattern occurs at in the strand, in order
+    pub fn locate<'b>(&self, pattern: impl Into<StrandSlice<'b, T>>) -> Vec<usize>
+    where
+        T: 'b,
+    {
+        let mut positions: Vec<usize> = self
+            .backward_search(pattern.into())
+            .map(|row| self.position(row))
+            .collect();
+        positions.sort_unstable();
+        positions
+    }
+
+    /// returns every position the pattern occurs at in the strand with at most
+    /// `mismatches` substitutions, in order.
+    ///
+    /// Backtracks through the index, trying every nucleotide at each position of the
+    /// pattern while mismatches remain, so the time grows with the alphabet size to the
+    /// power of `mismatches`.
+    pub fn locate_with_mismatches<'b>(
+        &self,
+        pattern: impl Into<StrandSlice<'b, T>>,
+        mismatches: usize,
+    ) -> Vec<usize>
+    where
+        T: 'b,
+    {
+        let pattern = pattern.into();
+        let mut rows = vec![];
+        self.backtrack(pattern, 0..self.bwt.len(), mismatches, &mut rows);
+        let mut positions: Vec<usize> = rows
+            .into_iter()
+            .flatten()
+            .map(|row| self.position(row))
+            .collect();
+        positions.sort_unstable();
+        positions
+    }
+
+    /// collects the rows of the suffixes matching the pattern with at most `mismatches`
+    /// substitutions, before the suffixes in the rows
+    fn backtrack(
+        &self,
+        pattern: StrandSlice<'_, T>,
+        rows: Range<usize>,
+        mismatches: usize,
+        found: &mut Vec<Range<usize>>,
+    ) {
+        let Some(last) = pattern.len().checked_sub(1) else {
+            found.push(rows);
+            return;
+        };
+        let prefix = pattern.slice(0..last);
+        for (index, nucleotide) in self.alphabet.iter().enumerate() {
+            let remaining = if *nucleotide == pattern[last] {
+                mismatches
+            } else if mismatches > 0 {
+                mismatches - 1
+            } else {
+                continue;
+            };
+            let extended = self.extend(index as u8 + 1, &rows);
+            if !extended.is_empty() {
+                self.backtrack(prefix, extended, remaining, found);
+            }
+        }
+    }
+}
+
+/// returns the strand with the Burrows-Wheeler transform, where None is the sentinel,
+/// or None if the transform does not have exactly one sentinel.
+///
+/// The first column of the sorted rotations is the sorted last column, and the nth
+/// occurrence of a nucleotide is the same in both, so the strand is read backwards by
+/// following each last nucleotide to its row in the first column.
+pub fn inverse_bwt<T: Nucleotide>(bwt: &[Option<T>]) -> Option<Strand<T>> {
+    if bwt.iter().filter(|n| n.is_none()).count() != 1 {
+        return None;
+    }
+    let mut sorted: Vec<(Option<T>, usize)> = bwt.iter().copied().zip(0..).collect();
+    sorted.sort_unstable();
+    // the row each row's last nucleotide starts in the first column
+    let mut first_rows = vec![0; bwt.len()];
+    for (first_row, (_, row)) in sorted.iter().enumerate() {
+        first_rows[*row] = first_row;
+    }
+    // the rotation starting with the sentinel is the first, and ends with the strand
+    let mut nucleotides = Vec::with_capacity(bwt.len() - 1);
+    let mut row = 0;
+    while let Some(nucleotide) = bwt[row] {
+        nucleotides.push(nucleotide);
+        row = first_rows[row];
+    }
+    nucleotides.reverse();
+    Some(Strand::new(nucleotides))
+}
+
+impl<T: Nucleotide> Strand<T> {
+    /// builds an FM-index of the strand, for searching it with many patterns
+    pub fn index(&self) -> StrandIndex<T> {
+        StrandIndex::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::RosalindError;
+    use crate::nucleotides::dna::DNA;
+
+    /// generates a pseudo-random strand, so failures can be reproduced
+    fn random_strand(seed: u64, length: usize) -> Strand<DNA> {
+        let mut state = seed;
+        let nucleotides = (0..length)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                [DNA::A, DNA::C, DNA::G, DNA::T][(state >> 62) as usize]
+            })
+            .collect();
+        Strand::new(nucleotides)
+    }
+
+    #[test]
+    fn test_bwt() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = "GCGTGCCTGGTCA".parse()?;
+        let index = strand.index();
+        let bwt: String = index
+            .bwt()
+            .iter()
+            .map(|n| n.map_or("$".to_string(), |n| format!("{:?}", n)))
+            .collect();
+        assert_eq!(bwt, "ACTGGCT$TGCGGC");
+        assert_eq!(index.to_strand(), strand);
+        assert!(inverse_bwt::<DNA>(&[Some(DNA::A)]).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_locate() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = "GATATATGCATATACTT".parse()?;
+        let index = strand.index();
+        for pattern in ["ATAT", "ACTT", "T", "GG", "GATATATGCATATACTT"] {
+            let pattern: Strand<DNA> = pattern.parse()?;
+            assert_eq!(index.locate(&pattern), pattern.substrings(&strand));
+            assert_eq!(index.count(&pattern), pattern.substrings(&strand).len());
+        }
+        let repeat: Strand<DNA> = "AAAA".parse()?;
+        let unknown: Strand<DNA> = "AC".parse()?;
+        assert!(repeat.index().locate(&unknown).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_locate_matches_substrings() {
+        // long enough for several sampled rows and counts
+        let strand = random_strand(1, 2000);
+        let index = strand.index();
+        assert_eq!(index.len(), 2000);
+        assert_eq!(index.to_strand(), strand);
+        for start in (0..1990).step_by(97) {
+            let pattern = strand.slice(start..start + 6);
+            assert_eq!(index.locate(pattern), pattern.substrings(&strand));
+        }
+    }
+
+    #[test]
+    fn test_bwt_filling_checkpoint_blocks() {
+        // BWTs of 64 and 65 rows, ending on and just after a checkpoint
+        for length in [63, 64] {
+            let strand = random_strand(length as u64, length);
+            let index = strand.index();
+            let pattern = strand.slice(10..14);
+            assert_eq!(index.count(pattern), pattern.substrings(&strand).len());
+            assert_eq!(index.locate(pattern), pattern.substrings(&strand));
+            let expected: Vec<usize> = strand
+                .windows(pattern.len())
+                .enumerate()
+                .filter(|(_, window)| window.distance(pattern).unwrap() <= 1)
+                .map(|(start, _)| start)
+                .collect();
+            assert_eq!(index.locate_with_mismatches(pattern, 1), expected);
+        }
+    }
+
+    #[test]
+    fn test_locate_with_mismatches() {
+        let strand = random_strand(2, 500);
+        let index = strand.index();
+        for (seed, mismatches) in [(3, 0), (4, 1), (5, 2)] {
+            let pattern = random_strand(seed, 5);
+            let expected: Vec<usize> = strand
+                .windows(pattern.len())
+                .enumerate()
+                .filter(|(_, window)| window.distance(&pattern).unwrap() <= mismatches)
+                .map(|(start, _)| start)
+                .collect();
+            assert_eq!(index.locate_with_mismatches(&pattern, mismatches), expected);
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        let strand: Strand<DNA> = Strand::new(vec![]);
+        let index = strand.index();
+        assert!(index.is_empty());
+        assert_eq!(index.to_strand(), strand);
+        assert_eq!(index.locate(&strand), vec![0]);
+    }
+}
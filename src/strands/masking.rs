@@ -0,0 +1,168 @@
+use super::Strand;
+use crate::nucleotides::{Degenerate, Nucleotide, Unknown};
+use std::ops::Range;
+
+impl<T: Nucleotide> Strand<T> {
+    /// parses the character, ignoring case, and appends it to the strand.
+    /// lowercase characters are appended as soft-masked.
+    /// fails with the character if it is not a nucleotide
+    pub fn push_char(&mut self, c: char) -> Result<(), char> {
+        let nucleotide = T::try_from(c.to_ascii_uppercase()).map_err(|_| c)?;
+        if c.is_ascii_lowercase() {
+            self.push_masked(nucleotide);
+        } else {
+            self.push(nucleotide);
+        }
+        Ok(())
+    }
+
+    /// appends the nucleotide as soft-masked
+    pub fn push_masked(&mut self, nucleotide: T) {
+        self.nucleotides.push(nucleotide);
+        self.soft_mask(self.len() - 1..self.len());
+    }
+
+    /// returns the ranges of soft-masked nucleotides, in order
+    pub fn masked_intervals(&self) -> &[Range<usize>] {
+        &self.mask
+    }
+
+    /// returns true if the nucleotide at the index is soft-masked
+    pub fn is_masked(&self, index: usize) -> bool {
+        let i = self.mask.partition_point(|r| r.end <= index);
+        i < self.mask.len() && self.mask[i].start <= index
+    }
+
+    /// soft-masks the range of nucleotides, merging it with any masked ranges it touches.
+    /// panics if the range extends past the end of the strand
+    pub fn soft_mask(&mut self, range: Range<usize>) {
+        assert!(
+            range.end <= self.len(),
+            "mask extends past the end of the strand"
+        );
+        if range.is_empty() {
+            return;
+        }
+        let first = self.mask.partition_point(|r| r.end < range.start);
+        let last = self.mask.partition_point(|r| r.start <= range.end);
+        let merged = if first < last {
+            range.start.min(self.mask[first].start)..range.end.max(self.mask[last - 1].end)
+        } else {
+            range
+        };
+        self.mask.splice(first..last, [merged]);
+    }
+
+    /// copies the range of nucleotides into a new strand, keeping the soft-mask of
+    /// the nucleotides in the range
+    pub fn substrand(&self, range: Range<usize>) -> Strand<T> {
+        let mut strand = Strand::new(self.nucleotides[range.clone()].to_vec());
+        for masked in &self.mask {
+            let start = masked.start.max(range.start);
+            let end = masked.end.min(range.end);
+            if start < end {
+                strand.soft_mask(start - range.start..end - range.start);
+            }
+        }
+        strand
+    }
+
+    /// removes the soft-mask from every nucleotide
+    pub fn clear_mask(&mut self) {
+        self.mask.clear();
+    }
+
+    /// converts the strand to the alphabet of ambiguity codes, keeping its soft-mask
+    pub fn to_degenerate<D: Degenerate<Base = T>>(&self) -> Strand<D> {
+        Strand {
+            nucleotides: self.nucleotides.iter().map(|n| D::from(*n)).collect(),
+            mask: self.mask.clone(),
+        }
+    }
+}
+
+impl<T: Unknown> Strand<T> {
+    /// returns a copy of the strand with every soft-masked nucleotide replaced
+    /// by the unknown nucleotide (e.g. N)
+    pub fn hard_mask(&self) -> Strand<T> {
+        let mut nucleotides = self.nucleotides.clone();
+        for range in &self.mask {
+            nucleotides[range.clone()].fill(T::UNKNOWN);
+        }
+        Strand::new(nucleotides)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::RosalindError;
+    use crate::nucleotides::degenerate::DegenerateDNA;
+    use crate::nucleotides::dna::DNA;
+
+    #[test]
+    fn test_parse_lowercase() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = "ACgtaCGtA".parse()?;
+        assert_eq!(strand.masked_intervals(), &[2..5, 7..8]);
+        assert!(strand.is_masked(4));
+        assert!(!strand.is_masked(5));
+        assert_eq!(format!("{}", strand), "ACgtaCGtA");
+        Ok(())
+    }
+
+    #[test]
+    fn test_soft_mask_merges() -> Result<(), RosalindError> {
+        let mut strand: Strand<DNA> = "ACgtaCGtACGT".parse()?;
+        strand.soft_mask(5..7);
+        assert_eq!(strand.masked_intervals(), &[Range { start: 2, end: 8 }]);
+        strand.soft_mask(10..12);
+        assert_eq!(format!("{}", strand), "ACgtacgtACgt");
+        Ok(())
+    }
+
+    #[test]
+    fn test_reverse_compliment_keeps_mask() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = "aaAACCCGgt".parse()?;
+        assert_eq!(format!("{}", strand.reverse_compliment()), "acCGGGTTtt");
+        Ok(())
+    }
+
+    #[test]
+    fn test_concat_keeps_mask() -> Result<(), RosalindError> {
+        let strand_1: Strand<DNA> = "ACgt".parse()?;
+        let strand_2: Strand<DNA> = "acGT".parse()?;
+        let strand = strand_1.concat(&strand_2);
+        assert_eq!(strand.masked_intervals(), &[Range { start: 2, end: 6 }]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_equality_ignores_mask() -> Result<(), RosalindError> {
+        let masked: Strand<DNA> = "acGT".parse()?;
+        let unmasked: Strand<DNA> = "ACGT".parse()?;
+        assert_eq!(masked, unmasked);
+        assert_ne!(masked.masked_intervals(), unmasked.masked_intervals());
+        let other: Strand<DNA> = "acGA".parse()?;
+        assert_ne!(masked, other);
+        Ok(())
+    }
+
+    #[test]
+    fn test_substrand_keeps_mask() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = "acGTaCGtA".parse()?;
+        assert_eq!(format!("{}", strand.substrand(1..8)), "cGTaCGt");
+        assert_eq!(format!("{}", strand.substrand(2..4)), "GT");
+        Ok(())
+    }
+
+    #[test]
+    fn test_hard_mask() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = "ACgtaCGtA".parse()?;
+        let expected: Strand<DegenerateDNA> = "ACNNNCGNA".parse()?;
+        assert_eq!(
+            strand.to_degenerate::<DegenerateDNA>().hard_mask(),
+            expected
+        );
+        Ok(())
+    }
+}
@@ -1,17 +1,47 @@
+use crate::error::RosalindError;
 use crate::nucleotides::{Complementable, Nucleotide, Transcribable};
 use std::collections::HashMap;
 use std::fmt;
-use std::ops::Index;
+use std::hash::{Hash, Hasher};
+use std::ops::{Index, Range};
 use std::str::FromStr;
 use std::vec::Vec;
 
 pub mod dna_strand;
+pub mod index;
+pub mod masking;
+pub mod packed;
 pub mod protein_strand;
 pub mod rna_strand;
+pub mod slice;
+pub mod suffixes;
+pub mod translation;
 
-#[derive(Eq, PartialEq)]
+pub use index::StrandIndex;
+pub use slice::StrandSlice;
+pub use suffixes::SuffixArray;
+
+#[derive(Clone)]
 pub struct Strand<T: Nucleotide> {
     nucleotides: Vec<T>,
+    /// the sorted, non-overlapping ranges of soft-masked (lowercase) nucleotides
+    mask: Vec<Range<usize>>,
+}
+
+/// Strands are equal if their nucleotides are, whatever their soft-masks.
+/// Compare `masked_intervals` as well where the masks matter.
+impl<T: Nucleotide> PartialEq for Strand<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.nucleotides == other.nucleotides
+    }
+}
+
+impl<T: Nucleotide> Eq for Strand<T> {}
+
+impl<T: Nucleotide> Hash for Strand<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.nucleotides.hash(state);
+    }
 }
 
 impl<T: Nucleotide> IntoIterator for Strand<T> {
@@ -22,33 +52,47 @@ impl<T: Nucleotide> IntoIterator for Strand<T> {
     }
 }
 
+impl<T: Nucleotide> Extend<T> for Strand<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.nucleotides.extend(iter);
+    }
+}
+
 impl<T: Nucleotide> FromStr for Strand<T> {
-    type Err = T::Error;
+    type Err = RosalindError;
 
+    /// parses the strand, ignoring case. Lowercase nucleotides are soft-masked.
     fn from_str(s: &str) -> Result<Strand<T>, Self::Err> {
-        let parse_results = s.chars().map(|c| T::try_from(c)).collect();
-        match parse_results {
-            Ok(results) => Ok(Strand {
-                nucleotides: results,
-            }),
-            Err(e) => Err(e),
+        let mut strand = Strand::new(Vec::with_capacity(s.len()));
+        for (position, c) in s.chars().enumerate() {
+            strand
+                .push_char(c)
+                .map_err(|symbol| RosalindError::InvalidSymbol {
+                    symbol,
+                    position,
+                    line: None,
+                })?;
         }
+        Ok(strand)
     }
 }
 
 impl<T: Nucleotide> Strand<T> {
     pub fn new(nucleotides: Vec<T>) -> Self {
         Strand {
-            nucleotides: nucleotides,
+            nucleotides,
+            mask: vec![],
         }
     }
 
     /// returns a new strand containing the two strands concatenated together
     pub fn concat(&self, other: &Strand<T>) -> Self {
-        let mut nucleotides = self.nucleotides.clone();
-        let other_copy = other.nucleotides.clone();
-        nucleotides.extend(other_copy);
-        Self::new(nucleotides)
+        let mut strand = self.clone();
+        strand.nucleotides.extend(other.nucleotides.iter());
+        for range in &other.mask {
+            strand.soft_mask(range.start + self.len()..range.end + self.len());
+        }
+        strand
     }
 
     pub fn push(&mut self, nucleotide: T) {
@@ -56,15 +100,7 @@ impl<T: Nucleotide> Strand<T> {
     }
 
     pub fn count_nucleotides(&self) -> HashMap<&T, u32> {
-        let mut map: HashMap<&T, u32> = HashMap::new();
-        for nucleotide in &self.nucleotides {
-            let mut current_count = 0;
-            if map.contains_key(nucleotide) {
-                current_count = map.get(nucleotide).unwrap().to_owned();
-            }
-            map.insert(nucleotide, current_count + 1);
-        }
-        map
+        self.as_slice().count_nucleotides()
     }
 
     pub fn transcribe<'a, Rhs>(&'a self) -> Strand<Rhs>
@@ -72,74 +108,104 @@ impl<T: Nucleotide> Strand<T> {
         Rhs: Transcribable<'a, T>,
         T: 'a,
     {
-        Strand::new(self.nucleotides.iter().map(|n| Rhs::from(&n)).collect())
+        Strand {
+            nucleotides: self.nucleotides.iter().map(|n| Rhs::from(n)).collect(),
+            mask: self.mask.clone(),
+        }
     }
 
-    /// returns the Hamming distance between the 2 strings.
-    /// panics if the strings are not of the same length
-    pub fn distance(&self, other: &Self) -> usize {
-        if self.nucleotides.len() != other.nucleotides.len() {
-            panic!()
-        }
-        self.nucleotides
-            .iter()
-            .zip(other.nucleotides.iter())
-            .filter(|(n1, n2)| **n1 != **n2)
-            .count()
+    /// returns the Hamming distance between the 2 strings. Ambiguity codes are
+    /// only counted as a difference if they cannot stand for the same nucleotide.
+    /// fails with LengthMismatch if the strings are not of the same length
+    pub fn distance<'b>(&self, other: impl Into<StrandSlice<'b, T>>) -> Result<usize, RosalindError>
+    where
+        T: 'b,
+    {
+        self.as_slice().distance(other)
     }
 
-    /// returns all indexes where this is a substring of other
-    pub fn substrings(&self, other: &Strand<T>) -> Vec<usize> {
-        let mut indexes = vec![];
-        if other.nucleotides.len() < self.nucleotides.len() {
-            return vec![];
-        }
-        for i in 0..(other.nucleotides.len() - self.nucleotides.len()) {
-            if self.is_substr_starting_from(other, i) {
-                indexes.push(i);
-            }
-        }
-        indexes
+    /// returns all indexes where this is a substring of other.
+    /// ambiguity codes match any nucleotide they could stand for
+    pub fn substrings<'b>(&self, other: impl Into<StrandSlice<'b, T>>) -> Vec<usize>
+    where
+        T: 'b,
+    {
+        self.as_slice().substrings(other)
     }
 
-    pub fn is_substr_starting_from(&self, other: &Strand<T>, start_index: usize) -> bool {
-        if other.nucleotides.len() <= self.nucleotides.len() + start_index {
-            return false;
-        }
-        for i in 0..self.nucleotides.len() {
-            if self.nucleotides[i] != other.nucleotides[i + start_index] {
-                return false;
-            }
-        }
-        true
+    pub fn is_substr_starting_from<'b>(
+        &self,
+        other: impl Into<StrandSlice<'b, T>>,
+        start_index: usize,
+    ) -> bool
+    where
+        T: 'b,
+    {
+        self.as_slice().is_substr_starting_from(other, start_index)
     }
 
     /// returns true is the suffix of the current strand overlaps with the prefix of other
     ///
     /// @length: the length of the overlap to test for
-    pub fn overlaps_with_length(&self, other: &Strand<T>, length: usize) -> bool {
-        if length == 0 || self.nucleotides.len() < length || other.nucleotides.len() < length {
-            return false;
-        }
-        for i in 0..length {
-            if self.nucleotides[self.nucleotides.len() - length + i] != other.nucleotides[i] {
-                return false;
-            }
-        }
-        true
+    pub fn overlaps_with_length<'b>(
+        &self,
+        other: impl Into<StrandSlice<'b, T>>,
+        length: usize,
+    ) -> bool
+    where
+        T: 'b,
+    {
+        self.as_slice().overlaps_with_length(other, length)
+    }
+
+    /// returns a view of the whole strand
+    pub fn as_slice(&self) -> StrandSlice<'_, T> {
+        StrandSlice::from(self)
+    }
+
+    /// returns a view of the range of the strand, without copying it
+    pub fn slice(&self, range: Range<usize>) -> StrandSlice<'_, T> {
+        StrandSlice::from(&self.nucleotides[range])
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.nucleotides.iter()
+    }
+
+    /// returns every view of `size` consecutive nucleotides, overlapping each other
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = StrandSlice<'_, T>> {
+        self.as_slice().windows(size)
+    }
+
+    /// returns consecutive views of `size` nucleotides. The last may be shorter.
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = StrandSlice<'_, T>> {
+        self.as_slice().chunks(size)
     }
 
     /// Returns the number of nucleotides in the strand, also referred to as its 'length'.
     pub fn len(&self) -> usize {
         self.nucleotides.len()
     }
+
+    /// Returns true if the strand contains no nucleotides.
+    pub fn is_empty(&self) -> bool {
+        self.nucleotides.is_empty()
+    }
 }
 
 impl<T: Complementable> Strand<T> {
     pub fn reverse_compliment(&self) -> Strand<T> {
         let mut rev = self.nucleotides.clone();
         rev.reverse();
-        Strand::new(rev.into_iter().map(|n| T::compliment(&n)).collect())
+        Strand {
+            nucleotides: rev.into_iter().map(|n| T::compliment(&n)).collect(),
+            mask: self
+                .mask
+                .iter()
+                .rev()
+                .map(|r| self.len() - r.end..self.len() - r.start)
+                .collect(),
+        }
     }
 }
 
@@ -149,12 +215,22 @@ impl<T: Nucleotide> fmt::Debug for Strand<T> {
     }
 }
 
+/// Soft-masked nucleotides are written in lowercase
 impl<T: Nucleotide> fmt::Display for Strand<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.nucleotides
+        let mut start = 0;
+        for range in &self.mask {
+            self.nucleotides[start..range.start]
+                .iter()
+                .try_for_each(|n| write!(f, "{:?}", n))?;
+            self.nucleotides[range.clone()]
+                .iter()
+                .try_for_each(|n| write!(f, "{}", format!("{:?}", n).to_lowercase()))?;
+            start = range.end;
+        }
+        self.nucleotides[start..]
             .iter()
-            .map(|n| write!(f, "{:?}", n))
-            .collect()
+            .try_for_each(|n| write!(f, "{:?}", n))
     }
 }
 
@@ -165,27 +241,33 @@ impl<T: Nucleotide> Index<usize> for Strand<T> {
     }
 }
 
+impl<T: Nucleotide> Index<Range<usize>> for Strand<T> {
+    type Output = [T];
+    fn index(&self, range: Range<usize>) -> &Self::Output {
+        &self.nucleotides[range]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::nucleotides::degenerate::DegenerateDNA;
     use crate::nucleotides::dna::DNA;
 
     #[test]
     fn test_count_nucleotides() {
-        let dna_string = Strand {
-            nucleotides: vec![
-                DNA::A,
-                DNA::C,
-                DNA::C,
-                DNA::G,
-                DNA::G,
-                DNA::G,
-                DNA::T,
-                DNA::T,
-                DNA::T,
-                DNA::T,
-            ],
-        };
+        let dna_string = Strand::new(vec![
+            DNA::A,
+            DNA::C,
+            DNA::C,
+            DNA::G,
+            DNA::G,
+            DNA::G,
+            DNA::T,
+            DNA::T,
+            DNA::T,
+            DNA::T,
+        ]);
 
         let results = dna_string.count_nucleotides();
 
@@ -196,7 +278,7 @@ mod tests {
     }
 
     #[test]
-    fn test_reverse_compliment() -> Result<(), char> {
+    fn test_reverse_compliment() -> Result<(), RosalindError> {
         let dna_string: Strand<DNA> = "AAAACCCGGT".parse()?;
         let expected: Strand<DNA> = "ACCGGGTTTT".parse()?;
         assert_eq!(dna_string.reverse_compliment(), expected);
@@ -207,7 +289,7 @@ mod tests {
     ($($name:ident: $value:expr,)*) => {
       $(
         #[test]
-        fn $name() -> Result<(), char> {
+        fn $name() -> Result<(), RosalindError> {
           let (str_1, str_2, start_index, expected) = $value;
           let strand_1: Strand<DNA> = str_1.parse()?;
           let strand_2: Strand<DNA> = str_2.parse()?;
@@ -222,24 +304,72 @@ mod tests {
       is_substr_starting_at_str_1_longer: ("GATATATGCATATACTT", "ATAT", 0, false),
       is_substr_starting_at_wrong_index: ("ATAT", "GATATATGCATATACTT", 0, false),
       is_substr_starting_at_right_index: ("ATAT", "GATATATGCATATACTT", 1, true),
+      is_substr_starting_at_last_index: ("ACTT", "GATATATGCATATACTT", 13, true),
+      is_substr_starting_past_end: ("ACTT", "GATATATGCATATACTT", 14, false),
     }
 
     #[test]
-    fn test_substrs() -> Result<(), char> {
+    fn test_substrs() -> Result<(), RosalindError> {
         let strand_1: Strand<DNA> = "GATATATGCATATACTT".parse()?;
         let strand_2: Strand<DNA> = "ATAT".parse()?;
         assert_eq!(strand_2.substrings(&strand_1), vec![1, 3, 9]);
+        let suffix: Strand<DNA> = "TACTT".parse()?;
+        assert_eq!(suffix.substrings(&strand_1), vec![12]);
+        assert_eq!(strand_1.substrings(&strand_1), vec![0]);
         Ok(())
     }
 
     #[test]
-    fn test_distance() -> Result<(), char> {
+    fn test_distance() -> Result<(), RosalindError> {
         let strand_1: Strand<DNA> = "GAGCCTACTAACGGGAT".parse()?;
         let strand_2: Strand<DNA> = "CATCGTAATGACGGCCT".parse()?;
-        assert_eq!(strand_1.distance(&strand_2), 7);
+        assert_eq!(strand_1.distance(&strand_2)?, 7);
         Ok(())
     }
 
+    #[test]
+    fn test_degenerate_distance() -> Result<(), RosalindError> {
+        let strand_1: Strand<DegenerateDNA> = "GANCRTAY".parse()?;
+        let strand_2: Strand<DegenerateDNA> = "GATCGTAA".parse()?;
+        assert_eq!(strand_1.distance(&strand_2)?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_degenerate_substrs() -> Result<(), RosalindError> {
+        let strand_1: Strand<DegenerateDNA> = "GATATATGCATATACTT".parse()?;
+        let strand_2: Strand<DegenerateDNA> = "AYAK".parse()?;
+        assert_eq!(strand_2.substrings(&strand_1), vec![1, 3, 9]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_distance_length_mismatch() -> Result<(), RosalindError> {
+        let strand_1: Strand<DNA> = "GAGCC".parse()?;
+        let strand_2: Strand<DNA> = "CAT".parse()?;
+        assert!(matches!(
+            strand_1.distance(&strand_2),
+            Err(RosalindError::LengthMismatch {
+                expected: 5,
+                actual: 3
+            })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_str_invalid_symbol() {
+        let result: Result<Strand<DNA>, RosalindError> = "GATXA".parse();
+        assert!(matches!(
+            result,
+            Err(RosalindError::InvalidSymbol {
+                symbol: 'X',
+                position: 3,
+                line: None
+            })
+        ));
+    }
+
     mod overlaps_with_length_tests {
         use super::*;
 
@@ -247,7 +377,7 @@ mod tests {
       ($($name:ident: $value:expr,)*) => {
         $(
           #[test]
-          fn $name() -> Result<(), char> {
+          fn $name() -> Result<(), RosalindError> {
             let (str_1, str_2, overlap_length, expected) = $value;
             let strand_1: Strand<DNA> = str_1.parse()?;
             let strand_2: Strand<DNA> = str_2.parse()?;
@@ -0,0 +1,332 @@
+use super::Strand;
+use crate::error::RosalindError;
+use crate::nucleotides::{Complementable, TwoBit};
+use std::collections::HashMap;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Index;
+use std::str::FromStr;
+
+/// the number of nucleotides stored in each word
+const BASES_PER_WORD: usize = 32;
+/// the low bit of every 2 bit code in a word
+const LOW_BITS: u64 = 0x5555_5555_5555_5555;
+
+/// A strand storing each nucleotide in 2 bits, a quarter of the memory of a Strand.
+/// Soft-masking is not kept when a strand is packed.
+#[derive(Clone, Eq, PartialEq)]
+pub struct PackedStrand<T: TwoBit> {
+    /// the nucleotide at position i is stored in bits 2 * (i % 32) of word i / 32.
+    /// the bits past the end of the strand are always zero.
+    words: Vec<u64>,
+    len: usize,
+    nucleotides: PhantomData<T>,
+}
+
+/// returns a word with the low bit of each code set where the code equals `code`
+fn matching_codes(word: u64, code: u8) -> u64 {
+    let diff = word ^ (code as u64 * LOW_BITS);
+    !(diff | diff >> 1) & LOW_BITS
+}
+
+/// reverses the order of the 2 bit codes in the word
+fn reverse_codes(word: u64) -> u64 {
+    let word = (word >> 2 & 0x3333_3333_3333_3333) | (word & 0x3333_3333_3333_3333) << 2;
+    let word = (word >> 4 & 0x0f0f_0f0f_0f0f_0f0f) | (word & 0x0f0f_0f0f_0f0f_0f0f) << 4;
+    word.swap_bytes()
+}
+
+impl<T: TwoBit> PackedStrand<T> {
+    pub fn new() -> Self {
+        PackedStrand {
+            words: vec![],
+            len: 0,
+            nucleotides: PhantomData,
+        }
+    }
+
+    pub fn push(&mut self, nucleotide: T) {
+        let offset = self.len % BASES_PER_WORD;
+        if offset == 0 {
+            self.words.push(0);
+        }
+        if let Some(word) = self.words.last_mut() {
+            *word |= (nucleotide.code() as u64) << (2 * offset);
+        }
+        self.len += 1;
+    }
+
+    fn code(&self, index: usize) -> usize {
+        (self.words[index / BASES_PER_WORD] >> (2 * (index % BASES_PER_WORD)) & 0b11) as usize
+    }
+
+    pub fn get(&self, index: usize) -> Option<T> {
+        (index < self.len).then(|| T::BASES[self.code(index)])
+    }
+
+    pub fn iter(&self) -> PackedIter<'_, T> {
+        PackedIter {
+            strand: self,
+            index: 0,
+            word: 0,
+        }
+    }
+
+    /// Returns the packed words. The first nucleotide is in the lowest bits of the first word.
+    pub fn as_words(&self) -> &[u64] {
+        &self.words
+    }
+
+    pub fn count_nucleotides(&self) -> HashMap<&T, u32> {
+        let mut counts = [0; 4];
+        for (code, count) in counts.iter_mut().enumerate().skip(1) {
+            *count = self
+                .words
+                .iter()
+                .map(|word| matching_codes(*word, code as u8).count_ones())
+                .sum();
+        }
+        // the padding after the end of the strand would be counted as the zero code
+        counts[0] = self.len as u32 - counts[1..].iter().sum::<u32>();
+        T::BASES
+            .iter()
+            .zip(counts)
+            .filter(|(_, count)| *count > 0)
+            .collect()
+    }
+
+    /// returns the fraction of the nucleotides that are G or C
+    pub fn get_gc_content(&self) -> f32 {
+        let gc_count: u32 = self
+            .words
+            .iter()
+            .map(|word| ((word ^ word >> 1) & LOW_BITS).count_ones())
+            .sum();
+        gc_count as f32 / self.len as f32
+    }
+
+    /// returns the Hamming distance between the 2 strands.
+    /// fails with LengthMismatch if the strands are not of the same length
+    pub fn distance(&self, other: &Self) -> Result<usize, RosalindError> {
+        if self.len != other.len {
+            return Err(RosalindError::LengthMismatch {
+                expected: self.len,
+                actual: other.len,
+            });
+        }
+        Ok(self
+            .words
+            .iter()
+            .zip(other.words.iter())
+            .map(|(w1, w2)| {
+                let diff = w1 ^ w2;
+                ((diff | diff >> 1) & LOW_BITS).count_ones() as usize
+            })
+            .sum())
+    }
+
+    /// Returns the number of nucleotides in the strand
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the strand contains no nucleotides
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Iterates over the nucleotides of a packed strand, unpacking a word at a time
+pub struct PackedIter<'a, T: TwoBit> {
+    strand: &'a PackedStrand<T>,
+    index: usize,
+    /// the codes of the current word not yet returned, in the lowest bits
+    word: u64,
+}
+
+impl<T: TwoBit> Iterator for PackedIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.index == self.strand.len {
+            return None;
+        }
+        if self.index.is_multiple_of(BASES_PER_WORD) {
+            self.word = self.strand.words[self.index / BASES_PER_WORD];
+        }
+        let nucleotide = T::BASES[(self.word & 0b11) as usize];
+        self.word >>= 2;
+        self.index += 1;
+        Some(nucleotide)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.strand.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: TwoBit> ExactSizeIterator for PackedIter<'_, T> {}
+
+impl<T: TwoBit + Complementable> PackedStrand<T> {
+    pub fn reverse_compliment(&self) -> Self {
+        let reversed: Vec<u64> = self.words.iter().rev().map(|w| reverse_codes(!w)).collect();
+        // the complemented padding is now at the start, so shift it out
+        let padding = 2 * (self.words.len() * BASES_PER_WORD - self.len);
+        let words = if padding == 0 {
+            reversed
+        } else {
+            (0..reversed.len())
+                .map(|i| {
+                    reversed[i] >> padding | reversed.get(i + 1).map_or(0, |w| w << (64 - padding))
+                })
+                .collect()
+        };
+        PackedStrand {
+            words,
+            len: self.len,
+            nucleotides: PhantomData,
+        }
+    }
+}
+
+impl<T: TwoBit> Default for PackedStrand<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: TwoBit> FromIterator<T> for PackedStrand<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut strand = PackedStrand::new();
+        iter.into_iter().for_each(|n| strand.push(n));
+        strand
+    }
+}
+
+impl<T: TwoBit> From<&Strand<T>> for PackedStrand<T> {
+    fn from(strand: &Strand<T>) -> Self {
+        strand.nucleotides.iter().copied().collect()
+    }
+}
+
+impl<T: TwoBit> From<&PackedStrand<T>> for Strand<T> {
+    fn from(strand: &PackedStrand<T>) -> Self {
+        Strand::new(strand.iter().collect())
+    }
+}
+
+impl<T: TwoBit> FromStr for PackedStrand<T> {
+    type Err = RosalindError;
+
+    /// parses the strand, ignoring case
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.chars()
+            .enumerate()
+            .map(|(position, c)| {
+                T::try_from(c.to_ascii_uppercase()).map_err(|_| RosalindError::InvalidSymbol {
+                    symbol: c,
+                    position,
+                    line: None,
+                })
+            })
+            .collect()
+    }
+}
+
+impl<T: TwoBit> Index<usize> for PackedStrand<T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        assert!(
+            index < self.len,
+            "index out of bounds: the len is {} but the index is {}",
+            self.len,
+            index
+        );
+        &T::BASES[self.code(index)]
+    }
+}
+
+impl<T: TwoBit> fmt::Debug for PackedStrand<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: TwoBit> fmt::Display for PackedStrand<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.iter().try_for_each(|n| write!(f, "{:?}", n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nucleotides::dna::DNA;
+    use crate::nucleotides::rna::RNA;
+
+    const GC_INPUT: &str =
+        "CCACCCTCGTGGTATGGCTAGGCATTCAGGAACCGGAGAACGCTTCAGACCAGCCCGGACTGGGAACCTGCGGGCAGTAGGTGGAAT";
+
+    #[test]
+    fn test_round_trip() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = GC_INPUT.parse()?;
+        let packed = PackedStrand::from(&strand);
+        assert_eq!(packed.len(), strand.len());
+        assert_eq!(packed.as_words().len(), 3);
+        assert_eq!(Strand::from(&packed), strand);
+        assert_eq!(format!("{}", packed), GC_INPUT);
+        assert_eq!(packed[40], DNA::C);
+        assert_eq!(packed.get(87), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_reverse_compliment() -> Result<(), RosalindError> {
+        for length in [0, 1, 31, 32, 33, 64, 87] {
+            let strand: Strand<DNA> = GC_INPUT[..length].parse()?;
+            let packed = PackedStrand::from(&strand);
+            assert_eq!(
+                packed.reverse_compliment(),
+                PackedStrand::from(&strand.reverse_compliment())
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_nucleotides() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = GC_INPUT.parse()?;
+        let packed = PackedStrand::from(&strand);
+        assert_eq!(packed.count_nucleotides(), strand.count_nucleotides());
+        let packed: PackedStrand<RNA> = "CCGG".parse()?;
+        assert_eq!(
+            packed.count_nucleotides(),
+            HashMap::from([(&RNA::C, 2), (&RNA::G, 2)])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_gc_content() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = GC_INPUT.parse()?;
+        let packed = PackedStrand::from(&strand);
+        assert_eq!(packed.get_gc_content(), strand.get_gc_content());
+        Ok(())
+    }
+
+    #[test]
+    fn test_distance() -> Result<(), RosalindError> {
+        let strand_1: PackedStrand<DNA> = "GAGCCTACTAACGGGAT".parse()?;
+        let strand_2: PackedStrand<DNA> = "CATCGTAATGACGGCCT".parse()?;
+        assert_eq!(strand_1.distance(&strand_2)?, 7);
+        assert!(matches!(
+            strand_1.distance(&"GAT".parse()?),
+            Err(RosalindError::LengthMismatch {
+                expected: 17,
+                actual: 3
+            })
+        ));
+        Ok(())
+    }
+}
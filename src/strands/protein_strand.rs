@@ -1,3 +1,5 @@
+use crate::error::RosalindError;
+use crate::nucleotides::genetic_code::GeneticCode;
 use crate::nucleotides::protein::AminoAcid;
 use crate::nucleotides::rna::RNA;
 use crate::strands::rna_strand::RNAReverseTranslations;
@@ -8,28 +10,69 @@ impl Strand<AminoAcid> {
         RNAReverseTranslations::new(self)
     }
 
+    pub fn reverse_translations_with_code(&self, code: &GeneticCode) -> RNAReverseTranslations {
+        RNAReverseTranslations::with_code(self, code)
+    }
+
+    /// returns the number of mRNA strands, including a final stop codon, that translate
+    /// to the protein, modulo `modulus`. fails with ZeroModulus if `modulus` is 0
+    pub fn count_mrna(&self, code: &GeneticCode, modulus: u64) -> Result<u64, RosalindError> {
+        if modulus == 0 {
+            return Err(RosalindError::ZeroModulus);
+        }
+        // multiplied in u128 so any u64 modulus cannot overflow
+        Ok(self
+            .nucleotides
+            .iter()
+            .chain([AminoAcid::Stop].iter())
+            .map(|amino_acid| code.reverse_translations(amino_acid).len() as u128)
+            .fold(1 % modulus, |count, codons| {
+                (count as u128 * codons % modulus as u128) as u64
+            }))
+    }
+
     pub fn weight(&self) -> f64 {
         self.nucleotides
             .iter()
-            .map(|aa| AminoAcid::get_monoisotopic_mass(aa))
+            .map(AminoAcid::get_monoisotopic_mass)
+            .sum()
+    }
+
+    /// returns the sum of the average masses of the residues
+    pub fn average_weight(&self) -> f64 {
+        self.nucleotides
+            .iter()
+            .map(AminoAcid::get_average_mass)
             .sum()
     }
 }
 
-impl From<Strand<RNA>> for Strand<AminoAcid> {
-    fn from(rna_strand: Strand<RNA>) -> Self {
-        if rna_strand.nucleotides.len() % 3 != 0 {
-            panic!()
+impl Strand<RNA> {
+    /// translates the strand with the genetic code, from the first M up to the first Stop.
+    /// fails with IncompleteCodon if the strand's length is not a multiple of 3
+    pub fn translate(&self, code: &GeneticCode) -> Result<Strand<AminoAcid>, RosalindError> {
+        if !self.nucleotides.len().is_multiple_of(3) {
+            return Err(RosalindError::IncompleteCodon {
+                length: self.nucleotides.len(),
+            });
         }
-        Strand {
-            nucleotides: rna_strand
-                .nucleotides
+        Ok(Strand::new(
+            self.nucleotides
                 .chunks_exact(3)
-                .map(|chunk| AminoAcid::from((chunk[0], chunk[1], chunk[2])))
+                .map(|chunk| code.translate((chunk[0], chunk[1], chunk[2])))
                 .skip_while(|aa| *aa != AminoAcid::M)
                 .take_while(|aa| *aa != AminoAcid::Stop)
                 .collect(),
-        }
+        ))
+    }
+}
+
+/// translates with the standard genetic code
+impl TryFrom<Strand<RNA>> for Strand<AminoAcid> {
+    type Error = RosalindError;
+
+    fn try_from(rna_strand: Strand<RNA>) -> Result<Self, Self::Error> {
+        rna_strand.translate(GeneticCode::standard())
     }
 }
 
@@ -38,12 +81,68 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_from_rna_strand() -> Result<(), char> {
+    fn test_from_rna_strand() -> Result<(), RosalindError> {
         let rna_strand: Strand<RNA> =
             "AUGGCCAUGGCGCCCAGAACUGAGAUCAAUAGUACCCGUAUUAACGGGUGA".parse()?;
-        let actual: Strand<AminoAcid> = Strand::from(rna_strand);
+        let actual: Strand<AminoAcid> = Strand::try_from(rna_strand)?;
         let expected: Strand<AminoAcid> = "MAMAPRTEINSTRING".parse()?;
         assert_eq!(actual, expected);
         Ok(())
     }
+
+    #[test]
+    fn test_weights() -> Result<(), RosalindError> {
+        let protein: Strand<AminoAcid> = "GAU*".parse()?;
+        assert!((protein.weight() - 279.01221).abs() < 1e-9);
+        assert!((protein.average_weight() - 278.1686).abs() < 1e-9);
+        assert_eq!(protein.to_string(), "GAU*");
+        Ok(())
+    }
+
+    #[test]
+    fn test_translate_mitochondrial() -> Result<(), RosalindError> {
+        let rna_strand: Strand<RNA> = "AUGUGAAGAUAA".parse()?;
+        let code = GeneticCode::from_id(2).unwrap();
+        assert_eq!(rna_strand.translate(code)?.to_string(), "MW");
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_mrna() -> Result<(), RosalindError> {
+        let protein: Strand<AminoAcid> = "MA".parse()?;
+        assert_eq!(protein.count_mrna(GeneticCode::standard(), 1_000_000)?, 12);
+        assert_eq!(
+            protein.count_mrna(GeneticCode::from_id(2).unwrap(), 1_000_000)?,
+            32
+        );
+        // 4^40 * 3 mRNAs, whose partial products modulo a large modulus overflow u64
+        let modulus = u64::MAX - 1;
+        let protein: Strand<AminoAcid> = "MA".repeat(40).parse()?;
+        let expected = (0..40).fold(3u128, |count, _| count * 4 % modulus as u128);
+        assert_eq!(
+            protein.count_mrna(GeneticCode::standard(), modulus)?,
+            expected as u64
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_mrna_zero_modulus() -> Result<(), RosalindError> {
+        let protein: Strand<AminoAcid> = "MA".parse()?;
+        assert!(matches!(
+            protein.count_mrna(GeneticCode::standard(), 0),
+            Err(RosalindError::ZeroModulus)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_rna_strand_incomplete_codon() -> Result<(), RosalindError> {
+        let rna_strand: Strand<RNA> = "AUGGC".parse()?;
+        assert!(matches!(
+            Strand::<AminoAcid>::try_from(rna_strand),
+            Err(RosalindError::IncompleteCodon { length: 5 })
+        ));
+        Ok(())
+    }
 }
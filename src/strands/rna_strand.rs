@@ -1,4 +1,5 @@
 use super::Strand;
+use crate::nucleotides::genetic_code::GeneticCode;
 use crate::nucleotides::protein::AminoAcid;
 use crate::nucleotides::rna::RNA;
 
@@ -11,16 +12,17 @@ impl Iterator for RNAReverseTranslations {
     type Item = Strand<RNA>;
 
     fn next(&mut self) -> std::option::Option<<Self as std::iter::Iterator>::Item> {
-        if let Some(next) = self.next_at_index(0) {
-            Some(Strand::new(next))
-        } else {
-            None
-        }
+        self.next_at_index(0).map(Strand::new)
     }
 }
 
 impl RNAReverseTranslations {
+    /// iterates over the reverse translations using the standard genetic code
     pub fn new(protein: &Strand<AminoAcid>) -> Self {
+        Self::with_code(protein, GeneticCode::standard())
+    }
+
+    pub fn with_code(protein: &Strand<AminoAcid>, code: &GeneticCode) -> Self {
         let length = protein.nucleotides.len();
 
         let mut indexes: Vec<usize> = Vec::with_capacity(length);
@@ -28,12 +30,12 @@ impl RNAReverseTranslations {
 
         for i in 0..length {
             indexes.push(0);
-            possible_translations.push(RNA::reverse_translations(&protein.nucleotides[i]));
+            possible_translations.push(code.reverse_translations(&protein.nucleotides[i]));
         }
 
         RNAReverseTranslations {
-            indexes: indexes,
-            possible_translations: possible_translations,
+            indexes,
+            possible_translations,
         }
     }
 
@@ -52,16 +54,16 @@ impl RNAReverseTranslations {
 
         if index == self.possible_translations.len() - 1 {
             self.indexes[index] += 1;
-            return Some(value);
+            Some(value)
         } else if let Some(next_index_value) = self.next_at_index(index + 1) {
             value.extend(next_index_value);
-            return Some(value);
+            Some(value)
         } else {
             self.indexes[index] += 1;
             for ii in index + 1..self.indexes.len() {
                 self.indexes[ii] = 0;
             }
-            return self.next_at_index(index);
+            self.next_at_index(index)
         }
     }
 }
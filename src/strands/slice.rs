@@ -0,0 +1,227 @@
+use super::Strand;
+use crate::error::RosalindError;
+use crate::nucleotides::Nucleotide;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Index, Range};
+
+/// A borrowed view of consecutive nucleotides of a strand. Slices do not keep
+/// the strand's soft-mask.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct StrandSlice<'a, T: Nucleotide> {
+    nucleotides: &'a [T],
+}
+
+impl<'a, T: Nucleotide> From<&'a [T]> for StrandSlice<'a, T> {
+    fn from(nucleotides: &'a [T]) -> Self {
+        StrandSlice { nucleotides }
+    }
+}
+
+impl<'a, T: Nucleotide> From<&'a Strand<T>> for StrandSlice<'a, T> {
+    fn from(strand: &'a Strand<T>) -> Self {
+        StrandSlice {
+            nucleotides: &strand.nucleotides,
+        }
+    }
+}
+
+impl<'a, T: Nucleotide> StrandSlice<'a, T> {
+    /// returns the view of the range of this slice
+    pub fn slice(&self, range: Range<usize>) -> StrandSlice<'a, T> {
+        StrandSlice::from(&self.nucleotides[range])
+    }
+
+    /// copies the nucleotides into a new strand
+    pub fn to_strand(&self) -> Strand<T> {
+        Strand::new(self.nucleotides.to_vec())
+    }
+
+    pub fn as_slice(&self) -> &'a [T] {
+        self.nucleotides
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'a, T> {
+        self.nucleotides.iter()
+    }
+
+    /// returns every view of `size` consecutive nucleotides, overlapping each other
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = StrandSlice<'a, T>> {
+        self.nucleotides.windows(size).map(StrandSlice::from)
+    }
+
+    /// returns consecutive views of `size` nucleotides. The last may be shorter.
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = StrandSlice<'a, T>> {
+        self.nucleotides.chunks(size).map(StrandSlice::from)
+    }
+
+    pub fn count_nucleotides(&self) -> HashMap<&'a T, u32> {
+        let mut map: HashMap<&T, u32> = HashMap::new();
+        for nucleotide in self.nucleotides {
+            *map.entry(nucleotide).or_insert(0) += 1;
+        }
+        map
+    }
+
+    /// returns the Hamming distance between the 2 strings. Ambiguity codes are
+    /// only counted as a difference if they cannot stand for the same nucleotide.
+    /// fails with LengthMismatch if the strings are not of the same length
+    pub fn distance<'b>(&self, other: impl Into<StrandSlice<'b, T>>) -> Result<usize, RosalindError>
+    where
+        T: 'b,
+    {
+        let other = other.into();
+        if self.len() != other.len() {
+            return Err(RosalindError::LengthMismatch {
+                expected: self.len(),
+                actual: other.len(),
+            });
+        }
+        Ok(self
+            .iter()
+            .zip(other.iter())
+            .filter(|(n1, n2)| !n1.matches(n2))
+            .count())
+    }
+
+    /// returns all indexes where this is a substring of other.
+    /// ambiguity codes match any nucleotide they could stand for
+    pub fn substrings<'b>(&self, other: impl Into<StrandSlice<'b, T>>) -> Vec<usize>
+    where
+        T: 'b,
+    {
+        let other = other.into();
+        let mut indexes = vec![];
+        if other.len() < self.len() {
+            return vec![];
+        }
+        for i in 0..=(other.len() - self.len()) {
+            if self.is_substr_starting_from(other, i) {
+                indexes.push(i);
+            }
+        }
+        indexes
+    }
+
+    pub fn is_substr_starting_from<'b>(
+        &self,
+        other: impl Into<StrandSlice<'b, T>>,
+        start_index: usize,
+    ) -> bool
+    where
+        T: 'b,
+    {
+        let other = other.into();
+        if other.len() < self.len() + start_index {
+            return false;
+        }
+        for i in 0..self.len() {
+            if !self[i].matches(&other[i + start_index]) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// returns true is the suffix of the current strand overlaps with the prefix of other
+    ///
+    /// @length: the length of the overlap to test for
+    pub fn overlaps_with_length<'b>(
+        &self,
+        other: impl Into<StrandSlice<'b, T>>,
+        length: usize,
+    ) -> bool
+    where
+        T: 'b,
+    {
+        let other = other.into();
+        if length == 0 || self.len() < length || other.len() < length {
+            return false;
+        }
+        self.nucleotides[self.len() - length..] == other.nucleotides[..length]
+    }
+
+    /// Returns the number of nucleotides in the slice
+    pub fn len(&self) -> usize {
+        self.nucleotides.len()
+    }
+
+    /// Returns true if the slice contains no nucleotides
+    pub fn is_empty(&self) -> bool {
+        self.nucleotides.is_empty()
+    }
+}
+
+impl<T: Nucleotide> Index<usize> for StrandSlice<'_, T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.nucleotides[index]
+    }
+}
+
+impl<T: Nucleotide> Index<Range<usize>> for StrandSlice<'_, T> {
+    type Output = [T];
+    fn index(&self, range: Range<usize>) -> &Self::Output {
+        &self.nucleotides[range]
+    }
+}
+
+impl<T: Nucleotide> fmt::Debug for StrandSlice<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.nucleotides).finish()
+    }
+}
+
+impl<T: Nucleotide> fmt::Display for StrandSlice<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.nucleotides
+            .iter()
+            .try_for_each(|n| write!(f, "{:?}", n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nucleotides::dna::DNA;
+
+    #[test]
+    fn test_slice() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = "GATATATGCATATACTT".parse()?;
+        let slice = strand.slice(1..5);
+        assert_eq!(format!("{}", slice), "ATAT");
+        assert_eq!(&strand[1..5], slice.as_slice());
+        assert_eq!(slice.substrings(&strand), vec![1, 3, 9]);
+        assert_eq!(format!("{}", slice.slice(2..4)), "AT");
+        assert_eq!(strand.slice(14..17).substrings(&strand), vec![14]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_distance_between_slices() -> Result<(), RosalindError> {
+        let strand_1: Strand<DNA> = "GAGCCTACTAACGGGAT".parse()?;
+        let strand_2: Strand<DNA> = "CATCGTAATGACGGCCT".parse()?;
+        assert_eq!(strand_1.slice(0..5).distance(strand_2.slice(0..5))?, 3);
+        assert_eq!(strand_1.distance(strand_2.slice(0..17))?, 7);
+        Ok(())
+    }
+
+    #[test]
+    fn test_windows_and_chunks() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = "GATTACA".parse()?;
+        let windows: Vec<String> = strand.windows(3).map(|w| w.to_string()).collect();
+        assert_eq!(windows, vec!["GAT", "ATT", "TTA", "TAC", "ACA"]);
+        let chunks: Vec<String> = strand.chunks(3).map(|c| c.to_string()).collect();
+        assert_eq!(chunks, vec!["GAT", "TAC", "A"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_overlaps_with_slice() -> Result<(), RosalindError> {
+        let strand_1: Strand<DNA> = "AAATAAA".parse()?;
+        let strand_2: Strand<DNA> = "AAATTTT".parse()?;
+        assert!(strand_1.slice(2..7).overlaps_with_length(&strand_2, 3));
+        assert!(!strand_1.overlaps_with_length(strand_2.slice(1..7), 3));
+        Ok(())
+    }
+}
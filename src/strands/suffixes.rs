@@ -0,0 +1,149 @@
+use super::{Strand, StrandSlice};
+use crate::nucleotides::Nucleotide;
+use crate::suffix_array::{alphabet, lcp_array, suffix_array, symbol};
+use std::ops::Range;
+
+/// The sorted suffixes of a strand, with the longest common prefix of each with the
+/// one before it. Built once in O(n) time, it answers substring queries without
+/// scanning the strand.
+///
+/// Nucleotides are compared exactly, so ambiguity codes only match themselves.
+pub struct SuffixArray<'a, T: Nucleotide> {
+    strand: StrandSlice<'a, T>,
+    suffixes: Vec<usize>,
+    lcp: Vec<usize>,
+}
+
+impl<'a, T: Nucleotide> SuffixArray<'a, T> {
+    pub fn new(strand: impl Into<StrandSlice<'a, T>>) -> Self {
+        let strand = strand.into();
+        let alphabet = alphabet(strand.iter());
+        let text: Vec<usize> = strand.iter().map(|n| symbol(&alphabet, n)).collect();
+        let suffixes = suffix_array(&text, alphabet.len());
+        let lcp = lcp_array(&text, &suffixes);
+        SuffixArray {
+            strand,
+            suffixes,
+            lcp,
+        }
+    }
+
+    /// returns the start of each suffix, in lexicographic order
+    pub fn suffixes(&self) -> &[usize] {
+        &self.suffixes
+    }
+
+    /// returns the length of the prefix each suffix shares with the one before it
+    pub fn lcp(&self) -> &[usize] {
+        &self.lcp
+    }
+
+    /// returns the range of the suffixes starting with the pattern, in O(m log n) time
+    fn matching<'b>(&self, pattern: StrandSlice<'b, T>) -> Range<usize> {
+        let nucleotides = self.strand.as_slice();
+        let prefix = |suffix: &usize| {
+            let end = (suffix + pattern.len()).min(nucleotides.len());
+            &nucleotides[*suffix..end]
+        };
+        let start = self
+            .suffixes
+            .partition_point(|suffix| prefix(suffix) < pattern.as_slice());
+        let end = self
+            .suffixes
+            .partition_point(|suffix| prefix(suffix) <= pattern.as_slice());
+        start..end
+    }
+
+    /// returns the number of times the pattern occurs in the strand
+    pub fn count<'b>(&self, pattern: impl Into<StrandSlice<'b, T>>) -> usize
+    where
+        T: 'b,
+    {
+        self.matching(pattern.into()).len()
+    }
+
+    /// returns every position the pattern occurs at in the strand, in order
+    pub fn locate<'b>(&self, pattern: impl Into<StrandSlice<'b, T>>) -> Vec<usize>
+    where
+        T: 'b,
+    {
+        let mut positions = self.suffixes[self.matching(pattern.into())].to_vec();
+        positions.sort_unstable();
+        positions
+    }
+
+    /// returns the first longest substring occurring more than once, or None if no
+    /// nucleotide repeats. The occurrences may overlap.
+    pub fn longest_repeat(&self) -> Option<StrandSlice<'a, T>> {
+        let (rank, length) = self
+            .lcp
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, length)| **length)?;
+        let start = self.suffixes[rank];
+        (*length > 0).then(|| self.strand.slice(start..start + length))
+    }
+
+    /// returns the number of distinct non-empty substrings of the strand. Each suffix
+    /// adds the prefixes it does not share with the one before it.
+    pub fn distinct_substrings(&self) -> usize {
+        let n = self.strand.len();
+        n * (n + 1) / 2 - self.lcp.iter().sum::<usize>()
+    }
+}
+
+impl<T: Nucleotide> Strand<T> {
+    /// builds the suffix array of the strand, for repeated substring queries
+    pub fn suffix_array(&self) -> SuffixArray<'_, T> {
+        SuffixArray::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::RosalindError;
+    use crate::nucleotides::dna::DNA;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_locate() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = "GATATATGCATATACTT".parse()?;
+        let pattern: Strand<DNA> = "ATAT".parse()?;
+        let suffix_array = strand.suffix_array();
+        assert_eq!(suffix_array.locate(&pattern), pattern.substrings(&strand));
+        assert_eq!(suffix_array.count(&pattern), 3);
+        let end: Strand<DNA> = "ACTT".parse()?;
+        assert_eq!(suffix_array.locate(&end), vec![13]);
+        let missing: Strand<DNA> = "GG".parse()?;
+        assert_eq!(suffix_array.count(&missing), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_longest_repeat() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = "ATATCGTTTTATCGTT".parse()?;
+        let suffix_array = strand.suffix_array();
+        assert_eq!(
+            suffix_array.longest_repeat().unwrap().to_string(),
+            "TATCGTT"
+        );
+        let unique: Strand<DNA> = "ACGT".parse()?;
+        assert!(unique.suffix_array().longest_repeat().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_distinct_substrings() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = "ATATCGTTTTATCGTT".parse()?;
+        let mut expected = HashSet::new();
+        for start in 0..strand.len() {
+            for end in start + 1..=strand.len() {
+                expected.insert(strand.slice(start..end).to_string());
+            }
+        }
+        assert_eq!(strand.suffix_array().distinct_substrings(), expected.len());
+        Ok(())
+    }
+}
@@ -0,0 +1,281 @@
+use super::Strand;
+use crate::nucleotides::dna::DNA;
+use crate::nucleotides::genetic_code::{Codon, GeneticCode};
+use crate::nucleotides::protein::AminoAcid;
+use crate::nucleotides::rna::RNA;
+use crate::nucleotides::Complementable;
+
+/// The strand a reading frame is read along
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Orientation {
+    Forward,
+    /// the reverse compliment of the strand
+    Reverse,
+}
+
+/// One of the six reading frames of a DNA strand
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Frame {
+    pub orientation: Orientation,
+    /// the position of the first codon, from 0 to 2
+    pub offset: usize,
+}
+
+impl Frame {
+    /// the three forward frames followed by the three reverse frames
+    pub const ALL: [Frame; 6] = [
+        Frame::new(Orientation::Forward, 0),
+        Frame::new(Orientation::Forward, 1),
+        Frame::new(Orientation::Forward, 2),
+        Frame::new(Orientation::Reverse, 0),
+        Frame::new(Orientation::Reverse, 1),
+        Frame::new(Orientation::Reverse, 2),
+    ];
+
+    pub const fn new(orientation: Orientation, offset: usize) -> Self {
+        Frame {
+            orientation,
+            offset,
+        }
+    }
+}
+
+/// An open reading frame: a start codon followed by codons up to and including a stop codon
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Orf {
+    pub frame: Frame,
+    /// the zero-based position of the ORF's first nucleotide on the forward strand
+    pub start: usize,
+    /// the position after the ORF's last nucleotide on the forward strand, including
+    /// the stop codon. Reverse ORFs are read from end to start.
+    pub end: usize,
+    /// the translated protein, without the stop
+    pub protein: Strand<AminoAcid>,
+}
+
+/// Options controlling which ORFs are found
+#[derive(Clone, Debug)]
+pub struct OrfOptions {
+    /// the minimum length of the protein, in amino acids
+    pub min_length: usize,
+    /// if true, ORFs starting at a start codon inside another ORF of the same frame
+    /// are also returned. Otherwise only the longest ORF ending at each stop is.
+    pub nested: bool,
+    pub genetic_code: &'static GeneticCode,
+    /// if true, ORFs may begin at any start codon of the genetic code, which is then
+    /// translated as M. Otherwise ORFs begin at codons translated to M.
+    pub alternative_starts: bool,
+}
+
+impl Default for OrfOptions {
+    fn default() -> Self {
+        OrfOptions {
+            min_length: 1,
+            nested: true,
+            genetic_code: GeneticCode::standard(),
+            alternative_starts: false,
+        }
+    }
+}
+
+impl Strand<DNA> {
+    /// translates every codon of the frame with the standard genetic code, including
+    /// stops. A trailing incomplete codon is ignored.
+    pub fn translate_frame(&self, frame: Frame) -> Strand<AminoAcid> {
+        self.translate_frame_with_code(frame, GeneticCode::standard())
+    }
+
+    pub fn translate_frame_with_code(&self, frame: Frame, code: &GeneticCode) -> Strand<AminoAcid> {
+        Strand::new(
+            self.frame_codons(frame)
+                .into_iter()
+                .map(|codon| code.translate(codon))
+                .collect(),
+        )
+    }
+
+    /// translates all six frames with the standard genetic code, in the order of Frame::ALL
+    pub fn six_frame_translation(&self) -> Vec<(Frame, Strand<AminoAcid>)> {
+        self.six_frame_translation_with_code(GeneticCode::standard())
+    }
+
+    pub fn six_frame_translation_with_code(
+        &self,
+        code: &GeneticCode,
+    ) -> Vec<(Frame, Strand<AminoAcid>)> {
+        Frame::ALL
+            .iter()
+            .map(|frame| (*frame, self.translate_frame_with_code(*frame, code)))
+            .collect()
+    }
+
+    /// returns the complete codons of the frame
+    fn frame_codons(&self, frame: Frame) -> Vec<Codon> {
+        let nucleotides: Vec<RNA> = match frame.orientation {
+            Orientation::Forward => self.nucleotides.iter().map(RNA::from).collect(),
+            Orientation::Reverse => self
+                .nucleotides
+                .iter()
+                .rev()
+                .map(|n| RNA::from(&n.compliment()))
+                .collect(),
+        };
+        nucleotides
+            .get(frame.offset..)
+            .unwrap_or_default()
+            .chunks_exact(3)
+            .map(|codon| (codon[0], codon[1], codon[2]))
+            .collect()
+    }
+
+    /// finds the open reading frames in all six frames. ORFs without a stop codon
+    /// before the end of the strand are not returned.
+    pub fn open_reading_frames(&self, options: &OrfOptions) -> Vec<Orf> {
+        let code = options.genetic_code;
+        let mut orfs = vec![];
+        for frame in Frame::ALL {
+            let codons = self.frame_codons(frame);
+            let protein = self.translate_frame_with_code(frame, code);
+            // the starts of the ORFs not yet closed by a stop
+            let mut starts = vec![];
+            for (i, codon) in codons.iter().enumerate() {
+                match protein[i] {
+                    AminoAcid::M => starts.push(i),
+                    _ if options.alternative_starts && code.is_start(*codon) => starts.push(i),
+                    AminoAcid::Stop => {
+                        let closed = if options.nested {
+                            &starts[..]
+                        } else {
+                            &starts[..starts.len().min(1)]
+                        };
+                        for start in closed.iter().filter(|s| i - **s >= options.min_length) {
+                            orfs.push(self.orf(frame, &protein, *start, i));
+                        }
+                        starts.clear();
+                    }
+                    _ => (),
+                }
+            }
+        }
+        orfs
+    }
+
+    /// builds the ORF from the amino acid at `start` to the stop at `stop`
+    fn orf(&self, frame: Frame, protein: &Strand<AminoAcid>, start: usize, stop: usize) -> Orf {
+        // alternative start codons are translated as M when they start a protein
+        let mut protein = protein.slice(start..stop).to_strand();
+        protein.nucleotides[0] = AminoAcid::M;
+        let begin = frame.offset + 3 * start;
+        let end = frame.offset + 3 * (stop + 1);
+        let (begin, end) = match frame.orientation {
+            Orientation::Forward => (begin, end),
+            Orientation::Reverse => (self.len() - end, self.len() - begin),
+        };
+        Orf {
+            frame,
+            start: begin,
+            end,
+            protein,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::RosalindError;
+    use std::collections::HashSet;
+
+    const INPUT: &str =
+        "AGCCATGTAGCTAACTCAGGTTACATGGGGATGACCCCGCGACTTGGATTAGAGTCTCTTTTGGAATAAGCCTGAATGATCCGAGTAGCATCTCAG";
+
+    #[test]
+    fn test_six_frame_translation() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = "ATGGCCTAA".parse()?;
+        let frames = strand.six_frame_translation();
+        assert_eq!(frames.len(), 6);
+        assert_eq!(frames[0].1.to_string(), "MA*");
+        assert_eq!(frames[1].1.to_string(), "WP");
+        assert_eq!(frames[3].0, Frame::new(Orientation::Reverse, 0));
+        assert_eq!(frames[3].1.to_string(), "LGH");
+        Ok(())
+    }
+
+    #[test]
+    fn test_open_reading_frames() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = INPUT.parse()?;
+        let proteins: HashSet<String> = strand
+            .open_reading_frames(&OrfOptions::default())
+            .iter()
+            .map(|orf| orf.protein.to_string())
+            .collect();
+        let expected = HashSet::from(
+            [
+                "MLLGSFRLIPKETLIQVAGSSPCNLS",
+                "M",
+                "MGMTPRLGLESLLE",
+                "MTPRLGLESLLE",
+            ]
+            .map(String::from),
+        );
+        assert_eq!(proteins, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_orf_coordinates() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = "CCATGAAATGATAGG".parse()?;
+        let orfs = strand.open_reading_frames(&OrfOptions::default());
+        assert_eq!(orfs.len(), 1);
+        assert_eq!((orfs[0].start, orfs[0].end), (2, 11));
+        assert_eq!(orfs[0].protein.to_string(), "MK");
+        let reverse: Strand<DNA> = strand.reverse_compliment();
+        let reverse_orfs = reverse.open_reading_frames(&OrfOptions::default());
+        let orf = reverse_orfs
+            .iter()
+            .find(|orf| orf.protein.to_string() == "MK")
+            .unwrap();
+        assert_eq!(orf.frame.orientation, Orientation::Reverse);
+        assert_eq!((orf.start, orf.end), (4, 13));
+        Ok(())
+    }
+
+    #[test]
+    fn test_alternative_starts() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = "GTGAAATTGCCCTAG".parse()?;
+        assert!(strand
+            .open_reading_frames(&OrfOptions::default())
+            .is_empty());
+        let options = OrfOptions {
+            genetic_code: GeneticCode::from_id(11).unwrap(),
+            alternative_starts: true,
+            ..OrfOptions::default()
+        };
+        let proteins: Vec<String> = strand
+            .open_reading_frames(&options)
+            .iter()
+            .map(|orf| orf.protein.to_string())
+            .collect();
+        assert_eq!(proteins, vec!["MKLP", "MP"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_orf_options() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = INPUT.parse()?;
+        let options = OrfOptions {
+            min_length: 2,
+            nested: false,
+            ..OrfOptions::default()
+        };
+        let proteins: HashSet<String> = strand
+            .open_reading_frames(&options)
+            .iter()
+            .map(|orf| orf.protein.to_string())
+            .collect();
+        let expected =
+            HashSet::from(["MLLGSFRLIPKETLIQVAGSSPCNLS", "MGMTPRLGLESLLE"].map(String::from));
+        assert_eq!(proteins, expected);
+        Ok(())
+    }
+}
@@ -0,0 +1,247 @@
+use crate::error::RosalindError;
+use crate::nucleotides::Nucleotide;
+use crate::strands::{Strand, StrandSlice};
+
+/// returns the positions in the strand of the first occurrence of the motif as a
+/// subsequence, its nucleotides in order but not necessarily next to each other,
+/// or None if it does not occur. Ambiguity codes match any nucleotide they could stand for.
+pub fn find_subsequence<'a, 'b, T>(
+    motif: impl Into<StrandSlice<'a, T>>,
+    strand: impl Into<StrandSlice<'b, T>>,
+) -> Option<Vec<usize>>
+where
+    T: Nucleotide + 'a + 'b,
+{
+    let (motif, strand) = (motif.into(), strand.into());
+    let mut positions = Vec::with_capacity(motif.len());
+    let mut nucleotides = strand.iter().enumerate();
+    for wanted in motif.iter() {
+        let (position, _) = nucleotides.find(|(_, n)| wanted.matches(n))?;
+        positions.push(position);
+    }
+    Some(positions)
+}
+
+/// The lengths of the longest common subsequences of every pair of prefixes of two
+/// strands, stored row by row
+struct LcsTable {
+    width: usize,
+    lengths: Vec<u32>,
+}
+
+impl LcsTable {
+    /// fills the table in O(nm) time and space. Nucleotides are only common to both
+    /// strands if they are the same.
+    fn new<T: Nucleotide>(strand_1: StrandSlice<'_, T>, strand_2: StrandSlice<'_, T>) -> Self {
+        let width = strand_2.len() + 1;
+        let mut lengths = vec![0; (strand_1.len() + 1) * width];
+        for (i, n1) in strand_1.iter().enumerate() {
+            for (j, n2) in strand_2.iter().enumerate() {
+                lengths[(i + 1) * width + j + 1] = if n1 == n2 {
+                    lengths[i * width + j] + 1
+                } else {
+                    lengths[i * width + j + 1].max(lengths[(i + 1) * width + j])
+                };
+            }
+        }
+        LcsTable { width, lengths }
+    }
+
+    /// returns the length of the longest common subsequence of the prefixes
+    fn get(&self, i: usize, j: usize) -> u32 {
+        self.lengths[i * self.width + j]
+    }
+}
+
+/// A step back through the LCS table
+enum Step<T> {
+    /// the nucleotide is in both strands
+    Common(T),
+    /// the nucleotide is only in the first strand
+    First(T),
+    /// the nucleotide is only in the second strand
+    Second(T),
+}
+
+/// returns the steps of a longest common subsequence through both strands, in order
+fn lcs_steps<T: Nucleotide>(
+    strand_1: StrandSlice<'_, T>,
+    strand_2: StrandSlice<'_, T>,
+) -> Vec<Step<T>> {
+    let table = LcsTable::new(strand_1, strand_2);
+    let mut steps = Vec::with_capacity(strand_1.len() + strand_2.len());
+    let (mut i, mut j) = (strand_1.len(), strand_2.len());
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && strand_1[i - 1] == strand_2[j - 1] {
+            i -= 1;
+            j -= 1;
+            steps.push(Step::Common(strand_1[i]));
+        } else if i > 0 && (j == 0 || table.get(i - 1, j) >= table.get(i, j - 1)) {
+            i -= 1;
+            steps.push(Step::First(strand_1[i]));
+        } else {
+            j -= 1;
+            steps.push(Step::Second(strand_2[j]));
+        }
+    }
+    steps.reverse();
+    steps
+}
+
+/// returns a longest strand that is a subsequence of both strands
+pub fn longest_common_subsequence<'a, 'b, T>(
+    strand_1: impl Into<StrandSlice<'a, T>>,
+    strand_2: impl Into<StrandSlice<'b, T>>,
+) -> Strand<T>
+where
+    T: Nucleotide + 'a + 'b,
+{
+    Strand::new(
+        lcs_steps(strand_1.into(), strand_2.into())
+            .into_iter()
+            .filter_map(|step| match step {
+                Step::Common(n) => Some(n),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+/// returns a shortest strand having both strands as subsequences, which interleaves
+/// the nucleotides of both around a longest common subsequence
+pub fn shortest_common_supersequence<'a, 'b, T>(
+    strand_1: impl Into<StrandSlice<'a, T>>,
+    strand_2: impl Into<StrandSlice<'b, T>>,
+) -> Strand<T>
+where
+    T: Nucleotide + 'a + 'b,
+{
+    Strand::new(
+        lcs_steps(strand_1.into(), strand_2.into())
+            .into_iter()
+            .map(|step| match step {
+                Step::Common(n) | Step::First(n) | Step::Second(n) => n,
+            })
+            .collect(),
+    )
+}
+
+/// returns the number of distinct longest common subsequences of the strands, modulo
+/// `modulus`. fails with ZeroModulus if `modulus` is 0
+pub fn count_longest_common_subsequences<'a, 'b, T>(
+    strand_1: impl Into<StrandSlice<'a, T>>,
+    strand_2: impl Into<StrandSlice<'b, T>>,
+    modulus: u64,
+) -> Result<u64, RosalindError>
+where
+    T: Nucleotide + 'a + 'b,
+{
+    if modulus == 0 {
+        return Err(RosalindError::ZeroModulus);
+    }
+    let (strand_1, strand_2) = (strand_1.into(), strand_2.into());
+    let table = LcsTable::new(strand_1, strand_2);
+    let width = table.width;
+    // summed in u128 so any u64 modulus cannot overflow
+    let modulus = modulus as u128;
+    // only the empty strand is common to an empty prefix
+    let mut counts = vec![1 % modulus; table.lengths.len()];
+    for i in 1..=strand_1.len() {
+        for j in 1..=strand_2.len() {
+            let length = table.get(i, j);
+            counts[i * width + j] = if strand_1[i - 1] == strand_2[j - 1] {
+                // every longest common subsequence ends with this nucleotide
+                counts[(i - 1) * width + j - 1]
+            } else {
+                // those of the two shorter prefixes, less those common to both
+                let mut count = 0;
+                if table.get(i - 1, j) == length {
+                    count += counts[(i - 1) * width + j];
+                }
+                if table.get(i, j - 1) == length {
+                    count += counts[i * width + j - 1];
+                }
+                if table.get(i - 1, j - 1) == length {
+                    count += modulus - counts[(i - 1) * width + j - 1];
+                }
+                count % modulus
+            };
+        }
+    }
+    Ok(counts[strand_1.len() * width + strand_2.len()] as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nucleotides::dna::DNA;
+    use crate::nucleotides::protein::AminoAcid;
+
+    #[test]
+    fn test_find_subsequence() -> Result<(), RosalindError> {
+        let strand: Strand<DNA> = "ACGTACGTGACG".parse()?;
+        let motif: Strand<DNA> = "GTA".parse()?;
+        assert_eq!(find_subsequence(&motif, &strand), Some(vec![2, 3, 4]));
+        let missing: Strand<DNA> = "GGGGG".parse()?;
+        assert_eq!(find_subsequence(&missing, &strand), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_longest_common_subsequence() -> Result<(), RosalindError> {
+        let strand_1: Strand<DNA> = "AACCTTGG".parse()?;
+        let strand_2: Strand<DNA> = "ACACTGTGA".parse()?;
+        let lcs = longest_common_subsequence(&strand_1, &strand_2);
+        assert_eq!(lcs.len(), 6);
+        assert!(find_subsequence(&lcs, &strand_1).is_some());
+        assert!(find_subsequence(&lcs, &strand_2).is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_shortest_common_supersequence() -> Result<(), RosalindError> {
+        let strand_1: Strand<DNA> = "ATCTGAT".parse()?;
+        let strand_2: Strand<DNA> = "TGCATA".parse()?;
+        let scs = shortest_common_supersequence(&strand_1, &strand_2);
+        assert_eq!(scs.len(), 9);
+        assert!(find_subsequence(&strand_1, &scs).is_some());
+        assert!(find_subsequence(&strand_2, &scs).is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_count_longest_common_subsequences() -> Result<(), RosalindError> {
+        let strand_1: Strand<AminoAcid> = "AB".parse()?;
+        let strand_2: Strand<AminoAcid> = "BA".parse()?;
+        assert_eq!(
+            count_longest_common_subsequences(&strand_1, &strand_2, 1_000_000_007)?,
+            2
+        );
+        // AACTGG, AACTTG, ACCTGG and ACCTTG
+        let strand_1: Strand<DNA> = "AACCTTGG".parse()?;
+        let strand_2: Strand<DNA> = "ACACTGTGA".parse()?;
+        assert_eq!(
+            count_longest_common_subsequences(&strand_1, &strand_2, 1_000_000_007)?,
+            4
+        );
+        assert_eq!(
+            count_longest_common_subsequences(&strand_1, &strand_2, 3)?,
+            1
+        );
+        // sums of counts near a modulus this large overflow u64
+        assert_eq!(
+            count_longest_common_subsequences(&strand_1, &strand_2, u64::MAX)?,
+            4
+        );
+        let strand: Strand<DNA> = "ACGT".repeat(8).parse()?;
+        let reversed: Strand<DNA> = "TGCA".repeat(8).parse()?;
+        let count = count_longest_common_subsequences(&strand, &reversed, u64::MAX)?;
+        let small = count_longest_common_subsequences(&strand, &reversed, 1_000_000_007)?;
+        assert_eq!(count % 1_000_000_007, small);
+        assert!(matches!(
+            count_longest_common_subsequences(&strand, &reversed, 0),
+            Err(RosalindError::ZeroModulus)
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,336 @@
+use crate::fasta::Fasta;
+use crate::nucleotides::Nucleotide;
+use crate::strands::Strand;
+use std::collections::{HashSet, VecDeque};
+
+/// marks an empty slot of a suffix array being built
+const EMPTY: usize = usize::MAX;
+
+/// returns the starts of the suffixes of the text in lexicographic order, where the
+/// symbols of the text are all less than `alphabet_size`. A suffix that is a prefix
+/// of another comes first.
+///
+/// Built with the SA-IS algorithm of Nong, Zhang and Chan, in O(n) time.
+pub fn suffix_array(text: &[usize], alphabet_size: usize) -> Vec<usize> {
+    // 0 is the sentinel, smaller than every symbol
+    let mut with_sentinel: Vec<usize> = text.iter().map(|symbol| symbol + 1).collect();
+    with_sentinel.push(0);
+    let suffixes = sa_is(&with_sentinel, alphabet_size + 1);
+    // the sentinel's suffix is always the first
+    suffixes[1..].to_vec()
+}
+
+/// sorts the suffixes of a text ending with a unique 0
+fn sa_is(text: &[usize], alphabet_size: usize) -> Vec<usize> {
+    let n = text.len();
+    if n == 1 {
+        return vec![0];
+    }
+    // a suffix is S-type if it is smaller than the next suffix, and L-type otherwise
+    let mut s_type = vec![false; n];
+    s_type[n - 1] = true;
+    for i in (0..n - 1).rev() {
+        s_type[i] = text[i] < text[i + 1] || (text[i] == text[i + 1] && s_type[i + 1]);
+    }
+    // the leftmost S-type suffixes, which follow an L-type suffix
+    let is_lms = |i: usize| i > 0 && s_type[i] && !s_type[i - 1];
+
+    let mut bucket_sizes = vec![0; alphabet_size];
+    for symbol in text {
+        bucket_sizes[*symbol] += 1;
+    }
+    let bucket_heads = || {
+        let mut heads = vec![0; alphabet_size];
+        for symbol in 1..alphabet_size {
+            heads[symbol] = heads[symbol - 1] + bucket_sizes[symbol - 1];
+        }
+        heads
+    };
+    let bucket_tails = || {
+        let mut tails = vec![0; alphabet_size];
+        let mut total = 0;
+        for (symbol, size) in bucket_sizes.iter().enumerate() {
+            total += size;
+            tails[symbol] = total;
+        }
+        tails
+    };
+    // sorts every suffix from the LMS suffixes, which must be in order for the
+    // result to be in order
+    let induce = |lms: &[usize]| {
+        let mut suffixes = vec![EMPTY; n];
+        let mut tails = bucket_tails();
+        for i in lms.iter().rev() {
+            tails[text[*i]] -= 1;
+            suffixes[tails[text[*i]]] = *i;
+        }
+        let mut heads = bucket_heads();
+        for k in 0..n {
+            let j = suffixes[k];
+            if j != EMPTY && j > 0 && !s_type[j - 1] {
+                suffixes[heads[text[j - 1]]] = j - 1;
+                heads[text[j - 1]] += 1;
+            }
+        }
+        let mut tails = bucket_tails();
+        for k in (0..n).rev() {
+            let j = suffixes[k];
+            if j != EMPTY && j > 0 && s_type[j - 1] {
+                tails[text[j - 1]] -= 1;
+                suffixes[tails[text[j - 1]]] = j - 1;
+            }
+        }
+        suffixes
+    };
+    // compares the LMS substrings, from one LMS position up to and including the next
+    let lms_equal = |a: usize, b: usize| {
+        for k in 0.. {
+            if text[a + k] != text[b + k] || s_type[a + k] != s_type[b + k] {
+                return false;
+            }
+            if k > 0 && (is_lms(a + k) || is_lms(b + k)) {
+                return is_lms(a + k) && is_lms(b + k);
+            }
+        }
+        unreachable!()
+    };
+
+    let lms: Vec<usize> = (0..n).filter(|i| is_lms(*i)).collect();
+    let sorted_lms: Vec<usize> = induce(&lms).into_iter().filter(|i| is_lms(*i)).collect();
+
+    // names the LMS substrings by their order, and sorts the LMS suffixes by sorting
+    // the text of names, which is at most half as long
+    let mut names = vec![EMPTY; n];
+    let mut name = 0;
+    names[sorted_lms[0]] = 0;
+    for pair in sorted_lms.windows(2) {
+        if !lms_equal(pair[0], pair[1]) {
+            name += 1;
+        }
+        names[pair[1]] = name;
+    }
+    let reduced: Vec<usize> = lms.iter().map(|i| names[*i]).collect();
+    let reduced_suffixes = if name + 1 == reduced.len() {
+        // every name is unique, so the names are already sorted
+        let mut suffixes = vec![0; reduced.len()];
+        for (i, name) in reduced.iter().enumerate() {
+            suffixes[*name] = i;
+        }
+        suffixes
+    } else {
+        sa_is(&reduced, name + 1)
+    };
+    let sorted_lms: Vec<usize> = reduced_suffixes.iter().map(|i| lms[*i]).collect();
+    induce(&sorted_lms)
+}
+
+/// returns the length of the longest common prefix of each suffix in the suffix array
+/// with the one before it, with 0 for the first suffix.
+///
+/// Built with Kasai's algorithm, in O(n) time.
+pub fn lcp_array(text: &[usize], suffixes: &[usize]) -> Vec<usize> {
+    let n = text.len();
+    let mut ranks = vec![0; n];
+    for (rank, suffix) in suffixes.iter().enumerate() {
+        ranks[*suffix] = rank;
+    }
+    let mut lcp = vec![0; n];
+    // the prefix shared with the previous suffix shrinks by at most 1 per position
+    let mut length: usize = 0;
+    for i in 0..n {
+        if ranks[i] == 0 {
+            length = 0;
+            continue;
+        }
+        let j = suffixes[ranks[i] - 1];
+        while i + length < n && j + length < n && text[i + length] == text[j + length] {
+            length += 1;
+        }
+        lcp[ranks[i]] = length;
+        length = length.saturating_sub(1);
+    }
+    lcp
+}
+
+/// returns the distinct nucleotides of the strands, in order. The position of a
+/// nucleotide is its symbol in the texts suffix arrays are built from.
+pub(crate) fn alphabet<'a, T: Nucleotide + 'a>(nucleotides: impl Iterator<Item = &'a T>) -> Vec<T> {
+    let mut alphabet: Vec<T> = nucleotides
+        .copied()
+        .collect::<HashSet<T>>()
+        .into_iter()
+        .collect();
+    alphabet.sort_unstable();
+    alphabet
+}
+
+/// returns the symbol of the nucleotide in the alphabet
+pub(crate) fn symbol<T: Nucleotide>(alphabet: &[T], nucleotide: &T) -> usize {
+    alphabet
+        .binary_search(nucleotide)
+        .expect("the alphabet has every nucleotide")
+}
+
+/// returns every distinct longest strand that is a substring of every record, in
+/// lexicographic order.
+///
+/// The records are joined with a unique separator after each into one text, and
+/// its suffix array is scanned with a sliding window for runs of suffixes from
+/// every record, whose shortest common prefix is shared by all of them. This takes
+/// O(n) time for records of n nucleotides in total.
+pub fn longest_common_substrings<T: Nucleotide>(records: &[Fasta<T>]) -> Vec<Strand<T>> {
+    match records {
+        [] => return vec![],
+        [record] => return vec![record.strand.clone()],
+        _ => (),
+    }
+    let alphabet = alphabet(records.iter().flat_map(|record| record.strand.iter()));
+    let mut text = vec![];
+    // the record of each position, or None for separators
+    let mut owners = vec![];
+    for (i, record) in records.iter().enumerate() {
+        text.extend(record.strand.iter().map(|n| symbol(&alphabet, n)));
+        owners.extend(std::iter::repeat_n(Some(i), record.len()));
+        text.push(alphabet.len() + i);
+        owners.push(None);
+    }
+    let suffixes = suffix_array(&text, alphabet.len() + records.len());
+    let lcp = lcp_array(&text, &suffixes);
+
+    // the number of suffixes of each record in the window
+    let mut counts = vec![0; records.len()];
+    let mut covered = 0;
+    // the positions of the window's lcp values that could become its minimum
+    let mut minimums: VecDeque<usize> = VecDeque::new();
+    let mut longest = 0;
+    let mut starts: Vec<usize> = vec![];
+    let mut left = 0;
+    for right in 0..suffixes.len() {
+        if let Some(owner) = owners[suffixes[right]] {
+            counts[owner] += 1;
+            if counts[owner] == 1 {
+                covered += 1;
+            }
+        }
+        if right > 0 {
+            while minimums.back().is_some_and(|back| lcp[*back] >= lcp[right]) {
+                minimums.pop_back();
+            }
+            minimums.push_back(right);
+        }
+        if covered < records.len() {
+            continue;
+        }
+        // drops suffixes from the left while the window still covers every record
+        loop {
+            match owners[suffixes[left]] {
+                Some(owner) if counts[owner] == 1 => break,
+                Some(owner) => counts[owner] -= 1,
+                None => (),
+            }
+            left += 1;
+        }
+        while minimums.front().is_some_and(|front| *front <= left) {
+            minimums.pop_front();
+        }
+        let length = minimums.front().map_or(0, |front| lcp[*front]);
+        if length > longest {
+            longest = length;
+            starts.clear();
+        }
+        if length == longest && length > 0 {
+            starts.push(suffixes[right]);
+        }
+    }
+
+    let mut substrings: Vec<Strand<T>> = vec![];
+    for start in starts {
+        let substring = Strand::new(
+            text[start..start + longest]
+                .iter()
+                .map(|symbol| alphabet[*symbol])
+                .collect(),
+        );
+        // windows sharing a substring are next to each other
+        if substrings.last() != Some(&substring) {
+            substrings.push(substring);
+        }
+    }
+    substrings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::RosalindError;
+    use crate::fasta::read_records;
+    use crate::nucleotides::dna::DNA;
+
+    /// sorts the suffixes by comparing them, in O(n^2 log n) time
+    fn naive_suffix_array(text: &[usize]) -> Vec<usize> {
+        let mut suffixes: Vec<usize> = (0..text.len()).collect();
+        suffixes.sort_by_key(|i| &text[*i..]);
+        suffixes
+    }
+
+    fn to_text(s: &str) -> Vec<usize> {
+        s.bytes().map(|b| (b - b'a') as usize).collect()
+    }
+
+    #[test]
+    fn test_suffix_array() {
+        let text = to_text("banana");
+        let suffixes = suffix_array(&text, 26);
+        assert_eq!(suffixes, vec![5, 3, 1, 0, 4, 2]);
+        assert_eq!(lcp_array(&text, &suffixes), vec![0, 1, 3, 0, 0, 2]);
+        let text = to_text("mississippi");
+        assert_eq!(suffix_array(&text, 26), naive_suffix_array(&text));
+        assert!(suffix_array(&[], 4).is_empty());
+    }
+
+    #[test]
+    fn test_suffix_array_matches_naive() {
+        let mut state: u64 = 7;
+        for length in 0..200 {
+            // small alphabets give the repeats that need recursion
+            let alphabet_size = 1 + length % 4;
+            let text: Vec<usize> = (0..length)
+                .map(|_| {
+                    state = state
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    (state >> 33) as usize % alphabet_size
+                })
+                .collect();
+            let suffixes = suffix_array(&text, alphabet_size);
+            assert_eq!(suffixes, naive_suffix_array(&text), "{:?}", text);
+            let lcp = lcp_array(&text, &suffixes);
+            for rank in 1..length {
+                let (a, b) = (&text[suffixes[rank - 1]..], &text[suffixes[rank]..]);
+                let expected = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+                assert_eq!(lcp[rank], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_longest_common_substrings() -> Result<(), RosalindError> {
+        let records: Vec<Fasta<DNA>> =
+            read_records([">1", "GATTACA", ">2", "TAGACCA", ">3", "ATACA"].into_iter())?;
+        let substrings: Vec<String> = longest_common_substrings(&records)
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(substrings, vec!["AC", "CA", "TA"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_common_substrings() -> Result<(), RosalindError> {
+        let records: Vec<Fasta<DNA>> = read_records([">1", "AAAA", ">2", "CCCC"].into_iter())?;
+        assert!(longest_common_substrings(&records).is_empty());
+        let records: Vec<Fasta<DNA>> = read_records([">1", "ACGT"].into_iter())?;
+        assert_eq!(longest_common_substrings(&records)[0].to_string(), "ACGT");
+        Ok(())
+    }
+}
@@ -1,10 +1,11 @@
+use rusty_rosalind::error::RosalindError;
 use rusty_rosalind::nucleotides::dna::DNA;
 use rusty_rosalind::nucleotides::rna::RNA;
 use rusty_rosalind::strands::Strand;
 use std::collections::HashMap;
 
 #[test]
-fn test_dna_question() -> Result<(), char> {
+fn test_dna_question() -> Result<(), RosalindError> {
   let parsed = "ATGCTTCAGAAAGGTCTTACG".parse::<Strand<DNA>>()?;
   let results = parsed.count_nucleotides();
   assert_eq!(
@@ -15,7 +16,7 @@ fn test_dna_question() -> Result<(), char> {
 }
 
 #[test]
-fn test_rna_question() -> Result<(), char> {
+fn test_rna_question() -> Result<(), RosalindError> {
   let dna_strand = "GATGGAACTTGACTACGTAAATT".parse::<Strand<DNA>>()?;
   let expected = "GAUGGAACUUGACUACGUAAAUU".parse::<Strand<RNA>>()?;
   let rna_strand: Strand<RNA> = dna_strand.transcribe();